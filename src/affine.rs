@@ -0,0 +1,164 @@
+use crate::{Vec3, Vec4, Mat3, Mat4, Rot, DVec3, DVec4, DMat3, DMat4, DRot};
+use crate::impl_ops::*;
+use core::ops::*;
+
+macro_rules! impl_affine3 {
+    ($Affine3:ident, $t:ident, $Vec3:ident, $Vec4:ident, $Mat3:ident, $Mat4:ident, $Rot:ident) => {
+        // Scale, then rotate, then translate. Composing and inverting is exact as long
+        // as the scale is uniform, otherwise the shear that a matrix would pick up is
+        // dropped, see Affine3::compose and Affine3::inverse.
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Affine3 {
+            pub scale: $Vec3,
+            pub rot: $Rot,
+            pub translation: $Vec3,
+        }
+
+        impl $Affine3 {
+            pub const fn new(scale: $Vec3, rot: $Rot, translation: $Vec3) -> Self {
+                Self { scale, rot, translation }
+            }
+
+            pub const IDENTITY: Self = Self::new($Vec3::ONE, $Rot::IDENTITY, $Vec3::ZERO);
+
+            pub const fn from_scale(scale: $Vec3) -> Self {
+                Self::new(scale, $Rot::IDENTITY, $Vec3::ZERO)
+            }
+
+            pub const fn from_rot(rot: $Rot) -> Self {
+                Self::new($Vec3::ONE, rot, $Vec3::ZERO)
+            }
+
+            pub const fn from_translation(translation: $Vec3) -> Self {
+                Self::new($Vec3::ONE, $Rot::IDENTITY, translation)
+            }
+
+            pub fn abs_diff_eq(self, other: $Affine3, max_abs_diff: $t) -> bool {
+                self.scale.abs_diff_eq(other.scale, max_abs_diff)
+                    && self.rot.abs_diff_eq(other.rot, max_abs_diff)
+                    && self.translation.abs_diff_eq(other.translation, max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Affine3, max_abs_diff: $t, max_relative: $t) -> bool {
+                self.scale.relative_eq(other.scale, max_abs_diff, max_relative)
+                    && self.rot.relative_eq(other.rot, max_abs_diff, max_relative)
+                    && self.translation.relative_eq(other.translation, max_abs_diff, max_relative)
+            }
+
+            // Expects a matrix without shear or projection
+            pub fn from_mat4(mat: $Mat4) -> Self {
+                let (scale, rot, translation) = mat.to_scale_rot_translation();
+                Self::new(scale, rot, translation)
+            }
+
+            pub fn to_mat4(self) -> $Mat4 {
+                let rot: $Mat3 = $Mat3::from_rot(self.rot);
+                let col1: $Vec3 = rot.col1 * self.scale.i;
+                let col2: $Vec3 = rot.col2 * self.scale.j;
+                let col3: $Vec3 = rot.col3 * self.scale.k;
+                $Mat4::from_cols(
+                    $Vec4::new(col1.i, col1.j, col1.k, 0.0),
+                    $Vec4::new(col2.i, col2.j, col2.k, 0.0),
+                    $Vec4::new(col3.i, col3.j, col3.k, 0.0),
+                    $Vec4::new(self.translation.i, self.translation.j, self.translation.k, 1.0),
+                )
+            }
+
+            pub fn transform_point(self, point: $Vec3) -> $Vec3 {
+                self.transform_vector(point) + self.translation
+            }
+
+            // Ignores the translation
+            pub fn transform_vector(self, vec: $Vec3) -> $Vec3 {
+                self.rot.rotate(vec.mul_elem(self.scale))
+            }
+
+            // Applies other first and then self, same as self * other. Only exact
+            // when self has a uniform scale or other has no rotation. Otherwise
+            // self's scale would shear other's rotation, which an Affine3 can't
+            // hold, so the scales and rotations are just multiplied and the
+            // shear is dropped. other's origin still lands in the right place.
+            // Use Affine3::try_compose to catch this, or go through Mat4.
+            pub fn compose(self, other: $Affine3) -> Self {
+                Self {
+                    scale: self.scale.mul_elem(other.scale),
+                    rot: self.rot * other.rot,
+                    translation: self.transform_point(other.translation),
+                }
+            }
+
+            // be careful, divide by zero error! Only exact when the scale is
+            // uniform or there is no rotation, the true inverse of a non-uniform
+            // scale then a rotation scales after rotating, which this can't hold.
+            // Use Affine3::try_inverse or go through Mat4 otherwise.
+            pub fn inverse(self) -> Self {
+                let scale: $Vec3 = $Vec3::ONE.div_elem(self.scale);
+                let rot: $Rot = self.rot.reverse();
+                Self {
+                    scale,
+                    rot,
+                    translation: -rot.rotate(self.translation).mul_elem(scale),
+                }
+            }
+
+            // None if self's scale would shear other's rotation, see
+            // Affine3::compose
+            pub fn try_compose(self, other: $Affine3) -> Option<Self> {
+                if !self.has_uniform_scale() && !other.has_no_rot() {
+                    return None;
+                }
+                Some(self.compose(other))
+            }
+
+            // None if a scale component is zero, or if the scale is non-uniform and
+            // there is a rotation, see Affine3::inverse
+            pub fn try_inverse(self) -> Option<Self> {
+                if self.scale.i * self.scale.j * self.scale.k == 0.0 || !self.scale.is_finite() {
+                    return None;
+                }
+                if !self.has_uniform_scale() && !self.has_no_rot() {
+                    return None;
+                }
+                Some(self.inverse())
+            }
+
+            // Equal scale components up to rounding, so the scale commutes with
+            // any rotation
+            fn has_uniform_scale(self) -> bool {
+                let tolerance: $t = 4.0 * $t::EPSILON * self.scale.abs().max_element();
+                (self.scale.i - self.scale.j).abs() <= tolerance
+                    && (self.scale.i - self.scale.k).abs() <= tolerance
+            }
+
+            fn has_no_rot(self) -> bool {
+                self.rot.abs_diff_eq($Rot::IDENTITY, 4.0 * $t::EPSILON)
+            }
+        }
+
+        impl Mul<$Affine3> for $Affine3 {
+            type Output = Self;
+            fn mul(self, val: Self) -> Self {
+                self.compose(val)
+            }
+        }
+
+        impl_ref_ops!($Affine3; Mul<$Affine3>, mul);
+        impl_assign_ops!($Affine3; MulAssign<$Affine3>, mul_assign, Mul, mul);
+    };
+}
+
+impl_affine3!(Affine3, f32, Vec3, Vec4, Mat3, Mat4, Rot);
+impl_affine3!(DAffine3, f64, DVec3, DVec4, DMat3, DMat4, DRot);
+
+impl Affine3 {
+    pub fn as_f64(self) -> DAffine3 {
+        DAffine3::new(self.scale.as_f64(), self.rot.as_f64(), self.translation.as_f64())
+    }
+}
+
+impl DAffine3 {
+    pub fn as_f32(self) -> Affine3 {
+        Affine3::new(self.scale.as_f32(), self.rot.as_f32(), self.translation.as_f32())
+    }
+}
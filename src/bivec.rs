@@ -1,28 +1,719 @@
-use crate::{Vec3, Vec4, Mat4};
+use crate::{math, Vec2, Vec3, Vec4, Rot2, Rot, Rot4, DVec2, DVec3, DVec4, DRot2, DRot, DRot4};
+use crate::impl_ops::*;
 use core::{f32, ops::*};
 
-const fn bivec3(b01: f32, b02: f32, b12: f32) -> Bivec3 {
-    Bivec3::new(b01, b02, b12)
+macro_rules! impl_bivec2 {
+    ($Bivec2:ident, $t:ident, $Vec2:ident, $Rot2:ident, $bivec2:ident) => {
+        #[allow(dead_code)]
+        const fn $bivec2(b01: $t) -> $Bivec2 {
+            $Bivec2::new(b01)
+        }
+
+        // A 2D bivector only has the I ^ J plane, so it is just a signed area
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Bivec2 {
+            pub b01: $t,
+        }
+
+        impl $Bivec2 {
+            pub const fn new(b01: $t) -> Self {
+                Self { b01 }
+            }
+
+            pub const fn from_array(array: [$t; 1]) -> Self {
+                Self::new(array[0])
+            }
+
+            pub const fn to_array(self) -> [$t; 1] {
+                [self.b01]
+            }
+
+            pub fn as_array(&self) -> &[$t; 1] {
+                unsafe { &*(self as *const Self as *const [$t; 1]) }
+            }
+
+            pub fn abs_diff_eq(self, other: $Bivec2, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Bivec2, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+            }
+
+            pub const ZERO: Self = Self::new(0.0);
+
+            // Unit plane I ^ J
+            pub const B01: Self = Self::new(1.0);
+
+            pub fn norm(self) -> $t {
+                self.b01.abs()
+            }
+
+            pub fn norm_squared(self) -> $t {
+                self.b01 * self.b01
+            }
+
+            // e^B = cos|B| + B/|B| sin|B|, which in 2D is just cos(b01) + sin(b01) I ^ J
+            pub fn exp(self) -> $Rot2 {
+                $Rot2::new(math::$t::cos(self.b01), math::$t::sin(self.b01))
+            }
+
+            pub fn wedge(u: $Vec2, v: $Vec2) -> $Bivec2 {
+                Self {
+                    b01: u.i * v.j - u.j * v.i,
+                }
+            }
+        }
+
+        impl Add<$Bivec2> for $Bivec2 {
+            type Output = Self;
+            fn add(self, val: Self) -> Self {
+                Self {
+                    b01: self.b01.add(val.b01),
+                }
+            }
+        }
+
+        impl Sub<$Bivec2> for $Bivec2 {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self {
+                Self {
+                    b01: self.b01.sub(val.b01),
+                }
+            }
+        }
+
+        impl Neg for $Bivec2 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    b01: -self.b01,
+                }
+            }
+        }
+
+        impl Mul<$t> for $Bivec2 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    b01: self.b01.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Bivec2 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    b01: self.b01.div(val),
+                }
+            }
+        }
+
+        impl Index<usize> for $Bivec2 {
+            type Output = $t;
+            fn index(&self, index: usize) -> &$t {
+                match index {
+                    0 => &self.b01,
+                    _ => panic!("index out of bounds: the len is 1 but the index is {}", index),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $Bivec2 {
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                match index {
+                    0 => &mut self.b01,
+                    _ => panic!("index out of bounds: the len is 1 but the index is {}", index),
+                }
+            }
+        }
+
+        impl_ref_ops!($Bivec2;
+            Add<$Bivec2>, add;
+            Sub<$Bivec2>, sub;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Bivec2;
+            AddAssign<$Bivec2>, add_assign, Add, add;
+            SubAssign<$Bivec2>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Bivec2, $t);
+        impl_ref_neg!($Bivec2);
+    };
 }
 
-#[derive(Debug, Copy, Clone)]
-pub struct Bivec3 {
-    pub b01: f32,
-    pub b02: f32,
-    pub b12: f32,
+macro_rules! impl_bivec3 {
+    ($Bivec3:ident, $t:ident, $Vec3:ident, $Rot:ident, $bivec3:ident) => {
+        #[allow(dead_code)]
+        const fn $bivec3(b01: $t, b02: $t, b12: $t) -> $Bivec3 {
+            $Bivec3::new(b01, b02, b12)
+        }
+
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Bivec3 {
+            pub b01: $t,
+            pub b02: $t,
+            pub b12: $t,
+        }
+
+
+        impl $Bivec3 {
+            pub const fn new(b01: $t, b02: $t, b12: $t) -> Self {
+                Self { b01, b02, b12 }
+            }
+
+            pub const fn from_array(array: [$t; 3]) -> Self {
+                Self::new(array[0], array[1], array[2])
+            }
+
+            pub const fn to_array(self) -> [$t; 3] {
+                [self.b01, self.b02, self.b12]
+            }
+
+            pub fn as_array(&self) -> &[$t; 3] {
+                unsafe { &*(self as *const Self as *const [$t; 3]) }
+            }
+
+            pub fn abs_diff_eq(self, other: $Bivec3, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Bivec3, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+            }
+
+            pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+
+            // Unit planes, B01 = I ^ J, B02 = I ^ K, B12 = J ^ K
+            pub const B01: Self = Self::new(1.0, 0.0, 0.0);
+
+            pub const B02: Self = Self::new(0.0, 1.0, 0.0);
+
+            pub const B12: Self = Self::new(0.0, 0.0, 1.0);
+
+            pub fn norm(self) -> $t {
+                math::$t::sqrt(self.norm_squared())
+            }
+
+            pub fn norm_squared(self) -> $t {
+                math::$t::powi(self.b01, 2)
+              + math::$t::powi(self.b02, 2)
+              + math::$t::powi(self.b12, 2)
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+
+            // None if the norm is zero, infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            pub fn normalize_or(self, fallback: $Bivec3) -> Self {
+                self.try_normalize().unwrap_or(fallback)
+            }
+
+            pub fn normalize_or_zero(self) -> Self {
+                self.normalize_or(Self::ZERO)
+            }
+
+            // Checks the squared norm against 1
+            pub fn is_normalized(self, epsilon: $t) -> bool {
+                (self.norm_squared() - 1.0).abs() <= epsilon
+            }
+
+            // Axis normal to the plane, the dual of I ^ J is K
+            pub fn dual(self) -> $Vec3 {
+                $Vec3::new(self.b12, -self.b02, self.b01)
+            }
+
+            // Plane normal to the axis, inverse of Bivec3::dual
+            pub fn from_dual(axis: $Vec3) -> Self {
+                Self {
+                    b01: axis.k,
+                    b02: -axis.j,
+                    b12: axis.i,
+                }
+            }
+
+            // Inner product vec · self, which lies in the plane and is perpendicular
+            // to vec. For an angular velocity this is the velocity of the point vec.
+            pub fn inner(self, vec: $Vec3) -> $Vec3 {
+                $Vec3::new(
+                    -vec.j * self.b01 - vec.k * self.b02,
+                    vec.i * self.b01 - vec.k * self.b12,
+                    vec.i * self.b02 + vec.j * self.b12,
+                )
+            }
+
+            // e^B = cos|B| + B/|B| sin|B|, falls back to the Taylor series of
+            // sin(x)/x for tiny bivectors
+            pub fn exp(self) -> $Rot {
+                let angle: $t = self.norm();
+                let sinc: $t = if angle > 1e-4 {
+                    math::$t::sin(angle) / angle
+                } else {
+                    1.0 - math::$t::powi(angle, 2) / 6.0
+                };
+                $Rot::new(math::$t::cos(angle), self.b01 * sinc, self.b02 * sinc, self.b12 * sinc)
+            }
+
+            pub fn wedge(u: $Vec3, v: $Vec3) -> $Bivec3 {
+                Self {
+                    b01: u.i * v.j - u.j * v.i,
+                    b02: u.i * v.k - u.k * v.i,
+                    b12: u.j * v.k - u.k * v.j,
+                }
+            }
+        }
+
+        impl Add<$Bivec3> for $Bivec3 {
+            type Output = Self;
+            fn add(self, val: Self) -> Self {
+                Self {
+                    b01: self.b01.add(val.b01),
+                    b02: self.b02.add(val.b02),
+                    b12: self.b12.add(val.b12),
+                }
+            }
+        }
+
+        impl Sub<$Bivec3> for $Bivec3 {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self {
+                Self {
+                    b01: self.b01.sub(val.b01),
+                    b02: self.b02.sub(val.b02),
+                    b12: self.b12.sub(val.b12),
+                }
+            }
+        }
+
+        impl Neg for $Bivec3 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b12: -self.b12,
+                }
+            }
+        }
+
+        impl Mul<$t> for $Bivec3 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    b01: self.b01.mul(val),
+                    b02: self.b02.mul(val),
+                    b12: self.b12.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Bivec3 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    b01: self.b01.div(val),
+                    b02: self.b02.div(val),
+                    b12: self.b12.div(val),
+                }
+            }
+        }
+
+        impl Index<usize> for $Bivec3 {
+            type Output = $t;
+            fn index(&self, index: usize) -> &$t {
+                match index {
+                    0 => &self.b01,
+                    1 => &self.b02,
+                    2 => &self.b12,
+                    _ => panic!("index out of bounds: the len is 3 but the index is {}", index),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $Bivec3 {
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                match index {
+                    0 => &mut self.b01,
+                    1 => &mut self.b02,
+                    2 => &mut self.b12,
+                    _ => panic!("index out of bounds: the len is 3 but the index is {}", index),
+                }
+            }
+        }
+
+        impl_ref_ops!($Bivec3;
+            Add<$Bivec3>, add;
+            Sub<$Bivec3>, sub;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Bivec3;
+            AddAssign<$Bivec3>, add_assign, Add, add;
+            SubAssign<$Bivec3>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Bivec3, $t);
+        impl_ref_neg!($Bivec3);
+    };
+}
+
+macro_rules! impl_bivec4 {
+    ($Bivec4:ident, $t:ident, $Vec4:ident, $Rot4:ident, $bivec4:ident) => {
+        #[allow(dead_code)]
+        const fn $bivec4(b01: $t, b02: $t, b03: $t, b12: $t, b13: $t, b23: $t) -> $Bivec4 {
+            $Bivec4::new(b01, b02, b03, b12, b13, b23)
+        }
+
+        // The six planes of 4D space, B03 = I ^ L and so on. Unlike in 3D a
+        // bivector is not always a single plane, e.g. B01 + B23 turns two
+        // planes at once.
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Bivec4 {
+            pub b01: $t,
+            pub b02: $t,
+            pub b03: $t,
+            pub b12: $t,
+            pub b13: $t,
+            pub b23: $t,
+        }
+
+        impl $Bivec4 {
+            pub const fn new(b01: $t, b02: $t, b03: $t, b12: $t, b13: $t, b23: $t) -> Self {
+                Self { b01, b02, b03, b12, b13, b23 }
+            }
+
+            pub const fn from_array(array: [$t; 6]) -> Self {
+                Self::new(array[0], array[1], array[2], array[3], array[4], array[5])
+            }
+
+            pub const fn to_array(self) -> [$t; 6] {
+                [self.b01, self.b02, self.b03, self.b12, self.b13, self.b23]
+            }
+
+            pub fn as_array(&self) -> &[$t; 6] {
+                unsafe { &*(self as *const Self as *const [$t; 6]) }
+            }
+
+            pub fn abs_diff_eq(self, other: $Bivec4, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Bivec4, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+            }
+
+            pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+            pub const B01: Self = Self::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+            pub const B02: Self = Self::new(0.0, 1.0, 0.0, 0.0, 0.0, 0.0);
+
+            pub const B03: Self = Self::new(0.0, 0.0, 1.0, 0.0, 0.0, 0.0);
+
+            pub const B12: Self = Self::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+            pub const B13: Self = Self::new(0.0, 0.0, 0.0, 0.0, 1.0, 0.0);
+
+            pub const B23: Self = Self::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+
+            pub fn norm(self) -> $t {
+                math::$t::sqrt(self.norm_squared())
+            }
+
+            pub fn norm_squared(self) -> $t {
+                math::$t::powi(self.b01, 2)
+              + math::$t::powi(self.b02, 2)
+              + math::$t::powi(self.b03, 2)
+              + math::$t::powi(self.b12, 2)
+              + math::$t::powi(self.b13, 2)
+              + math::$t::powi(self.b23, 2)
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+
+            // None if the norm is zero, infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            pub fn normalize_or(self, fallback: $Bivec4) -> Self {
+                self.try_normalize().unwrap_or(fallback)
+            }
+
+            pub fn normalize_or_zero(self) -> Self {
+                self.normalize_or(Self::ZERO)
+            }
+
+            // Checks the squared norm against 1
+            pub fn is_normalized(self, epsilon: $t) -> bool {
+                (self.norm_squared() - 1.0).abs() <= epsilon
+            }
+
+            // Plane perpendicular to each plane, the dual of I ^ J is K ^ L
+            pub fn dual(self) -> Self {
+                Self {
+                    b01: self.b23,
+                    b02: -self.b13,
+                    b03: self.b12,
+                    b12: self.b03,
+                    b13: -self.b02,
+                    b23: self.b01,
+                }
+            }
+
+            // Coefficient of I ^ J ^ K ^ L in self ^ self. Zero exactly when
+            // self is a single plane, i.e. the wedge of two vectors.
+            pub fn wedge_self(self) -> $t {
+                2.0 * (self.b01 * self.b23 - self.b02 * self.b13 + self.b03 * self.b12)
+            }
+
+            // Inner product vec · self, which lies in the planes and is
+            // perpendicular to vec
+            pub fn inner(self, vec: $Vec4) -> $Vec4 {
+                $Vec4::new(
+                    -vec.j * self.b01 - vec.k * self.b02 - vec.l * self.b03,
+                    vec.i * self.b01 - vec.k * self.b12 - vec.l * self.b13,
+                    vec.i * self.b02 + vec.j * self.b12 - vec.l * self.b23,
+                    vec.i * self.b03 + vec.j * self.b13 + vec.k * self.b23,
+                )
+            }
+
+            // Same as Bivec3::exp for a single plane. Otherwise self is split
+            // into two halves, (self ± dual) / 2, that square to scalars and
+            // commute, so each one is exponentiated on its own. The result can
+            // be a double rotation.
+            pub fn exp(self) -> $Rot4 {
+                let norm_squared: $t = self.norm_squared();
+                let wedge: $t = self.wedge_self();
+                let angle_plus: $t = math::$t::sqrt((norm_squared - wedge).max(0.0));
+                let angle_minus: $t = math::$t::sqrt((norm_squared + wedge).max(0.0));
+                let sinc = |angle: $t| -> $t {
+                    if angle > 1e-4 {
+                        math::$t::sin(angle) / angle
+                    } else {
+                        1.0 - math::$t::powi(angle, 2) / 6.0
+                    }
+                };
+                let cos_plus: $t = math::$t::cos(angle_plus);
+                let cos_minus: $t = math::$t::cos(angle_minus);
+                let sinc_plus: $t = sinc(angle_plus);
+                let sinc_minus: $t = sinc(angle_minus);
+                let plane: $Bivec4 = self * (0.5 * (sinc_plus + sinc_minus))
+                                   - self.dual() * (0.5 * (sinc_plus - sinc_minus));
+                $Rot4::new(
+                    0.5 * (cos_plus + cos_minus),
+                    plane.b01,
+                    plane.b02,
+                    plane.b03,
+                    plane.b12,
+                    plane.b13,
+                    plane.b23,
+                    0.5 * (cos_plus - cos_minus),
+                )
+            }
+
+            pub fn wedge(u: $Vec4, v: $Vec4) -> $Bivec4 {
+                Self {
+                    b01: u.i * v.j - u.j * v.i,
+                    b02: u.i * v.k - u.k * v.i,
+                    b03: u.i * v.l - u.l * v.i,
+                    b12: u.j * v.k - u.k * v.j,
+                    b13: u.j * v.l - u.l * v.j,
+                    b23: u.k * v.l - u.l * v.k,
+                }
+            }
+        }
+
+        impl Add<$Bivec4> for $Bivec4 {
+            type Output = Self;
+            fn add(self, val: Self) -> Self {
+                Self {
+                    b01: self.b01.add(val.b01),
+                    b02: self.b02.add(val.b02),
+                    b03: self.b03.add(val.b03),
+                    b12: self.b12.add(val.b12),
+                    b13: self.b13.add(val.b13),
+                    b23: self.b23.add(val.b23),
+                }
+            }
+        }
+
+        impl Sub<$Bivec4> for $Bivec4 {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self {
+                Self {
+                    b01: self.b01.sub(val.b01),
+                    b02: self.b02.sub(val.b02),
+                    b03: self.b03.sub(val.b03),
+                    b12: self.b12.sub(val.b12),
+                    b13: self.b13.sub(val.b13),
+                    b23: self.b23.sub(val.b23),
+                }
+            }
+        }
+
+        impl Neg for $Bivec4 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b03: -self.b03,
+                    b12: -self.b12,
+                    b13: -self.b13,
+                    b23: -self.b23,
+                }
+            }
+        }
+
+        impl Mul<$t> for $Bivec4 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    b01: self.b01.mul(val),
+                    b02: self.b02.mul(val),
+                    b03: self.b03.mul(val),
+                    b12: self.b12.mul(val),
+                    b13: self.b13.mul(val),
+                    b23: self.b23.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Bivec4 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    b01: self.b01.div(val),
+                    b02: self.b02.div(val),
+                    b03: self.b03.div(val),
+                    b12: self.b12.div(val),
+                    b13: self.b13.div(val),
+                    b23: self.b23.div(val),
+                }
+            }
+        }
+
+        impl Index<usize> for $Bivec4 {
+            type Output = $t;
+            fn index(&self, index: usize) -> &$t {
+                match index {
+                    0 => &self.b01,
+                    1 => &self.b02,
+                    2 => &self.b03,
+                    3 => &self.b12,
+                    4 => &self.b13,
+                    5 => &self.b23,
+                    _ => panic!("index out of bounds: the len is 6 but the index is {}", index),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $Bivec4 {
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                match index {
+                    0 => &mut self.b01,
+                    1 => &mut self.b02,
+                    2 => &mut self.b03,
+                    3 => &mut self.b12,
+                    4 => &mut self.b13,
+                    5 => &mut self.b23,
+                    _ => panic!("index out of bounds: the len is 6 but the index is {}", index),
+                }
+            }
+        }
+
+        impl_ref_ops!($Bivec4;
+            Add<$Bivec4>, add;
+            Sub<$Bivec4>, sub;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Bivec4;
+            AddAssign<$Bivec4>, add_assign, Add, add;
+            SubAssign<$Bivec4>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Bivec4, $t);
+        impl_ref_neg!($Bivec4);
+    };
 }
 
+impl_bivec2!(Bivec2, f32, Vec2, Rot2, bivec2);
+impl_bivec2!(DBivec2, f64, DVec2, DRot2, dbivec2);
+
+impl_bivec3!(Bivec3, f32, Vec3, Rot, bivec3);
+impl_bivec3!(DBivec3, f64, DVec3, DRot, dbivec3);
+
+impl_bivec4!(Bivec4, f32, Vec4, Rot4, bivec4);
+impl_bivec4!(DBivec4, f64, DVec4, DRot4, dbivec4);
+
+impl Bivec2 {
+    pub fn as_f64(self) -> DBivec2 {
+        DBivec2::new(self.b01 as f64)
+    }
+}
+
+impl DBivec2 {
+    pub fn as_f32(self) -> Bivec2 {
+        Bivec2::new(self.b01 as f32)
+    }
+}
 
 impl Bivec3 {
-    pub const fn new(b01: f32, b02: f32, b12: f32) -> Self {
-        Self { b01, b02, b12 }
+    pub fn as_f64(self) -> DBivec3 {
+        DBivec3::new(self.b01 as f64, self.b02 as f64, self.b12 as f64)
     }
+}
 
-    pub fn wedge(u: Vec3, v: Vec3) -> Bivec3 {
-        Self {
-            b01: u.i * v.j - u.j * v.i,
-            b02: u.i * v.k - u.k * v.i,
-            b12: u.j * v.k - u.k * v.j,
-        }
+impl DBivec3 {
+    pub fn as_f32(self) -> Bivec3 {
+        Bivec3::new(self.b01 as f32, self.b02 as f32, self.b12 as f32)
+    }
+}
+
+impl Bivec4 {
+    pub fn as_f64(self) -> DBivec4 {
+        DBivec4::from_array(self.to_array().map(|x| x as f64))
+    }
+}
+
+impl DBivec4 {
+    pub fn as_f32(self) -> Bivec4 {
+        Bivec4::from_array(self.to_array().map(|x| x as f32))
     }
 }
@@ -0,0 +1,35 @@
+// Order the three Euler angles are applied in, X, Y and Z being the I, J and
+// K axes. The rotations are about the fixed axes, so XYZ turns about X first,
+// then Y, then Z, the same as Z * Y * X when composing rotors or matrices.
+// Each one is counterclockwise looking down its axis towards the origin.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    // Axis indices in the order they are applied
+    pub(crate) const fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+        }
+    }
+
+    // 1 when the axes are a cyclic order of X, Y, Z, -1 otherwise
+    pub(crate) const fn parity(self) -> i8 {
+        match self {
+            EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY => 1,
+            EulerOrder::XZY | EulerOrder::YXZ | EulerOrder::ZYX => -1,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+// approx traits on top of the inherent abs_diff_eq and relative_eq
+
+use crate::*;
+use approx::{AbsDiffEq, RelativeEq};
+
+macro_rules! impl_approx {
+    ($t:ident, $($T:ident),+) => {
+        $(
+            impl AbsDiffEq for $T {
+                type Epsilon = $t;
+
+                fn default_epsilon() -> $t {
+                    $t::EPSILON
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                    $T::abs_diff_eq(*self, *other, epsilon)
+                }
+            }
+
+            impl RelativeEq for $T {
+                fn default_max_relative() -> $t {
+                    $t::EPSILON
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                    $T::relative_eq(*self, *other, epsilon, max_relative)
+                }
+            }
+        )+
+    };
+}
+
+impl_approx!(f32, Vec2, Vec3, Vec4, Mat2, Mat3, Mat4, Bivec2, Bivec3, Bivec4, Rot2, Rot, Rot4, Multivec3, Point, Line, Plane, Motor, Affine3);
+impl_approx!(f64, DVec2, DVec3, DVec4, DMat2, DMat3, DMat4, DBivec2, DBivec3, DBivec4, DRot2, DRot, DRot4, DMultivec3, DPoint, DLine, DPlane, DMotor, DAffine3);
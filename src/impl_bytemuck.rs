@@ -0,0 +1,17 @@
+// Every type is repr(C) and made of floats only, so there is no padding and
+// any bit pattern is valid
+
+use crate::*;
+use bytemuck::{Pod, Zeroable};
+
+macro_rules! impl_pod {
+    ($($T:ident),+) => {
+        $(
+            unsafe impl Zeroable for $T {}
+            unsafe impl Pod for $T {}
+        )+
+    };
+}
+
+impl_pod!(Vec2, Vec3, Vec4, Mat2, Mat3, Mat4, Bivec2, Bivec3, Bivec4, Rot2, Rot, Rot4, Multivec3, Point, Line, Plane, Motor, Affine3);
+impl_pod!(DVec2, DVec3, DVec4, DMat2, DMat3, DMat4, DBivec2, DBivec3, DBivec4, DRot2, DRot, DRot4, DMultivec3, DPoint, DLine, DPlane, DMotor, DAffine3);
@@ -0,0 +1,90 @@
+// Conversions to and from mint. Vector components i, j, k, l map to x, y, z, w
+// and matrices map to column matrices.
+//
+// A rotor a + b01 e01 + b02 e02 + b12 e12 rotates the same way as the
+// quaternion with s = a, x = -b12, y = b02, z = -b01. The plane of rotation
+// is the dual of the quaternion's axis, and the rotor's bivector is the
+// negated plane, hence the signs.
+
+use crate::*;
+use mint::IntoMint;
+
+macro_rules! impl_mint_vec {
+    ($Vec:ident, $Mint:ident, $t:ident, ($($field:ident: $mint_field:ident),+)) => {
+        impl From<mint::$Mint<$t>> for $Vec {
+            fn from(vec: mint::$Mint<$t>) -> Self {
+                Self { $($field: vec.$mint_field),+ }
+            }
+        }
+
+        impl From<$Vec> for mint::$Mint<$t> {
+            fn from(vec: $Vec) -> Self {
+                Self { $($mint_field: vec.$field),+ }
+            }
+        }
+
+        impl IntoMint for $Vec {
+            type MintType = mint::$Mint<$t>;
+        }
+    };
+}
+
+macro_rules! impl_mint_mat {
+    ($Mat:ident, $Mint:ident, $t:ident, ($($col:ident: $mint_col:ident),+)) => {
+        impl From<mint::$Mint<$t>> for $Mat {
+            fn from(mat: mint::$Mint<$t>) -> Self {
+                Self { $($col: mat.$mint_col.into()),+ }
+            }
+        }
+
+        impl From<$Mat> for mint::$Mint<$t> {
+            fn from(mat: $Mat) -> Self {
+                Self { $($mint_col: mat.$col.into()),+ }
+            }
+        }
+
+        impl IntoMint for $Mat {
+            type MintType = mint::$Mint<$t>;
+        }
+    };
+}
+
+macro_rules! impl_mint_rot {
+    ($Rot:ident, $t:ident) => {
+        impl From<mint::Quaternion<$t>> for $Rot {
+            fn from(quat: mint::Quaternion<$t>) -> Self {
+                Self::new(quat.s, -quat.v.z, quat.v.y, -quat.v.x)
+            }
+        }
+
+        impl From<$Rot> for mint::Quaternion<$t> {
+            fn from(rot: $Rot) -> Self {
+                Self {
+                    v: mint::Vector3 { x: -rot.b12, y: rot.b02, z: -rot.b01 },
+                    s: rot.a,
+                }
+            }
+        }
+
+        impl IntoMint for $Rot {
+            type MintType = mint::Quaternion<$t>;
+        }
+    };
+}
+
+impl_mint_vec!(Vec2, Vector2, f32, (i: x, j: y));
+impl_mint_vec!(DVec2, Vector2, f64, (i: x, j: y));
+impl_mint_vec!(Vec3, Vector3, f32, (i: x, j: y, k: z));
+impl_mint_vec!(DVec3, Vector3, f64, (i: x, j: y, k: z));
+impl_mint_vec!(Vec4, Vector4, f32, (i: x, j: y, k: z, l: w));
+impl_mint_vec!(DVec4, Vector4, f64, (i: x, j: y, k: z, l: w));
+
+impl_mint_mat!(Mat2, ColumnMatrix2, f32, (col1: x, col2: y));
+impl_mint_mat!(DMat2, ColumnMatrix2, f64, (col1: x, col2: y));
+impl_mint_mat!(Mat3, ColumnMatrix3, f32, (col1: x, col2: y, col3: z));
+impl_mint_mat!(DMat3, ColumnMatrix3, f64, (col1: x, col2: y, col3: z));
+impl_mint_mat!(Mat4, ColumnMatrix4, f32, (col1: x, col2: y, col3: z, col4: w));
+impl_mint_mat!(DMat4, ColumnMatrix4, f64, (col1: x, col2: y, col3: z, col4: w));
+
+impl_mint_rot!(Rot, f32);
+impl_mint_rot!(DRot, f64);
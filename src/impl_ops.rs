@@ -0,0 +1,82 @@
+// Operator impls that are the same for every type, built on top of the by
+// value impls in each type's own module
+
+// &a op &b, &a op b and a op &b
+macro_rules! impl_ref_ops {
+    ($T:ident; $($Trait:ident<$Rhs:ident>, $method:ident);+ $(;)?) => {
+        $(
+            impl<'a> $Trait<&'a $Rhs> for $T {
+                type Output = <$T as $Trait<$Rhs>>::Output;
+                fn $method(self, val: &'a $Rhs) -> Self::Output {
+                    $Trait::$method(self, *val)
+                }
+            }
+
+            impl<'a> $Trait<$Rhs> for &'a $T {
+                type Output = <$T as $Trait<$Rhs>>::Output;
+                fn $method(self, val: $Rhs) -> Self::Output {
+                    $Trait::$method(*self, val)
+                }
+            }
+
+            impl<'a, 'b> $Trait<&'a $Rhs> for &'b $T {
+                type Output = <$T as $Trait<$Rhs>>::Output;
+                fn $method(self, val: &'a $Rhs) -> Self::Output {
+                    $Trait::$method(*self, *val)
+                }
+            }
+        )+
+    };
+}
+
+// a op= b as a = a op b
+macro_rules! impl_assign_ops {
+    ($T:ident; $($AssignTrait:ident<$Rhs:ident>, $assign_method:ident, $Trait:ident, $method:ident);+ $(;)?) => {
+        $(
+            impl $AssignTrait<$Rhs> for $T {
+                fn $assign_method(&mut self, val: $Rhs) {
+                    *self = $Trait::$method(*self, val);
+                }
+            }
+
+            impl<'a> $AssignTrait<&'a $Rhs> for $T {
+                fn $assign_method(&mut self, val: &'a $Rhs) {
+                    *self = $Trait::$method(*self, *val);
+                }
+            }
+        )+
+    };
+}
+
+// Scalar on the left, s * a as a * s
+macro_rules! impl_scalar_lhs_mul {
+    ($T:ident, $t:ident) => {
+        impl Mul<$T> for $t {
+            type Output = $T;
+            fn mul(self, val: $T) -> $T {
+                val * self
+            }
+        }
+
+        impl<'a> Mul<&'a $T> for $t {
+            type Output = $T;
+            fn mul(self, val: &'a $T) -> $T {
+                *val * self
+            }
+        }
+    };
+}
+
+// Unary minus for references
+macro_rules! impl_ref_neg {
+    ($T:ident) => {
+        impl<'a> Neg for &'a $T {
+            type Output = $T;
+            fn neg(self) -> $T {
+                -*self
+            }
+        }
+    };
+}
+
+pub(crate) use {impl_assign_ops, impl_ref_neg, impl_ref_ops, impl_scalar_lhs_mul};
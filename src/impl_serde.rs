@@ -0,0 +1,78 @@
+// Vectors, bivectors and rotors are tuples of their components, matrices are
+// flat arrays in column major order
+
+use crate::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! impl_serde_tuple {
+    ($T:ident, ($($field:ident),+)) => {
+        // The trailing commas keep Bivec2 a one element tuple
+        impl Serialize for $T {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ($(self.$field,)+).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $T {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let ($($field,)+) = Deserialize::deserialize(deserializer)?;
+                Ok(Self { $($field),+ })
+            }
+        }
+    };
+}
+
+macro_rules! impl_serde_mat {
+    ($Mat:ident, $t:ident, $n:literal) => {
+        impl Serialize for $Mat {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.to_cols_array().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $Mat {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let array: [$t; $n] = Deserialize::deserialize(deserializer)?;
+                Ok(Self::from_cols_array(&array))
+            }
+        }
+    };
+}
+
+impl_serde_tuple!(Vec2, (i, j));
+impl_serde_tuple!(DVec2, (i, j));
+impl_serde_tuple!(Vec3, (i, j, k));
+impl_serde_tuple!(DVec3, (i, j, k));
+impl_serde_tuple!(Vec4, (i, j, k, l));
+impl_serde_tuple!(DVec4, (i, j, k, l));
+impl_serde_tuple!(Bivec2, (b01));
+impl_serde_tuple!(DBivec2, (b01));
+impl_serde_tuple!(Bivec3, (b01, b02, b12));
+impl_serde_tuple!(DBivec3, (b01, b02, b12));
+impl_serde_tuple!(Bivec4, (b01, b02, b03, b12, b13, b23));
+impl_serde_tuple!(DBivec4, (b01, b02, b03, b12, b13, b23));
+impl_serde_tuple!(Rot2, (a, b01));
+impl_serde_tuple!(DRot2, (a, b01));
+impl_serde_tuple!(Rot, (a, b01, b02, b12));
+impl_serde_tuple!(DRot, (a, b01, b02, b12));
+impl_serde_tuple!(Rot4, (a, b01, b02, b03, b12, b13, b23, q0123));
+impl_serde_tuple!(DRot4, (a, b01, b02, b03, b12, b13, b23, q0123));
+impl_serde_tuple!(Multivec3, (a, i, j, k, b01, b02, b12, t012));
+impl_serde_tuple!(DMultivec3, (a, i, j, k, b01, b02, b12, t012));
+impl_serde_tuple!(Point, (i, j, k, w));
+impl_serde_tuple!(DPoint, (i, j, k, w));
+impl_serde_tuple!(Line, (b01, b02, b12, bw0, bw1, bw2));
+impl_serde_tuple!(DLine, (b01, b02, b12, bw0, bw1, bw2));
+impl_serde_tuple!(Plane, (i, j, k, w));
+impl_serde_tuple!(DPlane, (i, j, k, w));
+impl_serde_tuple!(Motor, (a, b01, b02, b12, bw0, bw1, bw2, qw012));
+impl_serde_tuple!(DMotor, (a, b01, b02, b12, bw0, bw1, bw2, qw012));
+impl_serde_tuple!(Affine3, (scale, rot, translation));
+impl_serde_tuple!(DAffine3, (scale, rot, translation));
+
+impl_serde_mat!(Mat2, f32, 4);
+impl_serde_mat!(DMat2, f64, 4);
+impl_serde_mat!(Mat3, f32, 9);
+impl_serde_mat!(DMat3, f64, 9);
+impl_serde_mat!(Mat4, f32, 16);
+impl_serde_mat!(DMat4, f64, 16);
@@ -1,3 +1,13 @@
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+// Every type is generated for f32 and for f64, the f64 versions are prefixed
+// with a D, e.g. Vec3 and DVec3
+
+mod impl_ops;
+
 pub mod vec2;
 pub use self::vec2::*;
 
@@ -22,4 +32,31 @@ pub use self::bivec::*;
 pub mod rotor;
 pub use self::rotor::*;
 
+pub mod multivec;
+pub use self::multivec::*;
+
+pub mod pga;
+pub use self::pga::*;
+
+pub mod affine;
+pub use self::affine::*;
+
+pub mod euler;
+pub use self::euler::*;
+
+#[cfg(feature = "simd")]
+mod simd;
+
+mod math;
+
+#[cfg(feature = "approx")]
+mod impl_approx;
+
+#[cfg(feature = "bytemuck")]
+mod impl_bytemuck;
+
+#[cfg(feature = "mint")]
+mod impl_mint;
 
+#[cfg(feature = "serde")]
+mod impl_serde;
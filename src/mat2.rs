@@ -1,100 +1,266 @@
-use crate::vec2::Vec2;
+use crate::{math, Vec2, Rot2, DVec2, DRot2};
+use crate::impl_ops::*;
 use core::{f32, ops::*};
 
-#[derive(Debug, Clone, Copy)]
-pub struct Mat2 {
-    pub col1: Vec2,
-    pub col2: Vec2,
-}
+macro_rules! impl_mat2 {
+    ($Mat2:ident, $t:ident, $Vec2:ident, $Rot2:ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(C)]
+        pub struct $Mat2 {
+            pub col1: $Vec2,
+            pub col2: $Vec2,
+        }
 
-impl Mat2 {
-    const fn new(
-        m00: f32, m01: f32,
-        m10: f32, m11: f32,
-    ) -> Self {
-        Self {
-            col1: Vec2::new(m00, m10),
-            col2: Vec2::new(m01, m11),
+        impl $Mat2 {
+            const fn new(
+                m00: $t, m01: $t,
+                m10: $t, m11: $t,
+            ) -> Self {
+                Self {
+                    col1: $Vec2::new(m00, m10),
+                    col2: $Vec2::new(m01, m11),
+                }
+            }
+
+            pub const fn from_cols(vec1: $Vec2, vec2: $Vec2) -> Self {
+                Self {
+                    col1: vec1,
+                    col2: vec2,
+                }
+            }
+
+            // Column major
+            pub const fn from_cols_array(array: &[$t; 4]) -> Self {
+                Self::from_cols(
+                    $Vec2::new(array[0], array[1]),
+                    $Vec2::new(array[2], array[3]),
+                )
+            }
+
+            // Column major
+            pub const fn to_cols_array(self) -> [$t; 4] {
+                [
+                    self.col1.i, self.col1.j,
+                    self.col2.i, self.col2.j,
+                ]
+            }
+
+            pub fn abs_diff_eq(self, other: $Mat2, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_cols_array(), &other.to_cols_array(), max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Mat2, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_cols_array(), &other.to_cols_array(), max_abs_diff, max_relative)
+            }
+
+            // Not recommended, this is slow
+            pub const fn from_rows(row1: $Vec2, row2: $Vec2) -> Self {
+                Self {
+                    col1: $Vec2::new(row1.i, row2.i),
+                    col2: $Vec2::new(row1.j, row2.j),
+                }
+            }
+
+            pub const I: Self = Self::from_cols($Vec2::I, $Vec2::J);
+
+            pub const ZERO: Self = Self::from_cols($Vec2::ZERO, $Vec2::ZERO);
+
+            pub fn from_rot2(rot: $Rot2) -> Self {
+                Self::from_cols(rot.rotate($Vec2::I), rot.rotate($Vec2::J))
+            }
+
+            // Component-wise product
+            pub fn mul_elem(self, other: $Mat2) -> Self {
+                Self {
+                    col1: self.col1.mul_elem(other.col1),
+                    col2: self.col2.mul_elem(other.col2),
+                }
+            }
+
+            // Component-wise quotient
+            pub fn div_elem(self, other: $Mat2) -> Self {
+                Self {
+                    col1: self.col1.div_elem(other.col1),
+                    col2: self.col2.div_elem(other.col2),
+                }
+            }
+
+            pub const fn transpose(self) -> Self {
+                Self::from_rows(self.col1, self.col2)
+            }
+
+            pub fn determinant(self) -> $t {
+                self.col1.i * self.col2.j - self.col2.i * self.col1.j
+            }
+
+            // be careful, divide by zero error!
+            pub fn inverse(self) -> Self {
+                let det_inv: $t = 1.0 / self.determinant();
+                Self::new(
+                    self.col2.j * det_inv, -self.col2.i * det_inv,
+                    -self.col1.j * det_inv, self.col1.i * det_inv,
+                )
+            }
+
+            // Returns None if the matrix is singular
+            pub fn try_inverse(self) -> Option<Self> {
+                let det: $t = self.determinant();
+                if det == 0.0 || !det.is_finite() {
+                    return None;
+                }
+                Some(self.inverse())
+            }
         }
-    }
 
-    pub const fn from_cols(vec1: Vec2, vec2: Vec2) -> Self {
-        Self {
-            col1: vec1,
-            col2: vec2,
+        impl Add<$Mat2> for $Mat2 {
+            type Output = Self;
+            fn add(self, mat: Self) -> Self{
+                Self {
+                    col1: self.col1.add(mat.col1),
+                    col2: self.col2.add(mat.col2),
+                }
+            }
         }
-    }
 
-    // Not recommended, this is slow
-    pub const fn from_rows(row1: Vec2, row2: Vec2) -> Self {
-        Self {
-            col1: Vec2::new(row1.i, row2.i),
-            col2: Vec2::new(row1.j, row2.j),
+        impl Add<$t> for $Mat2 {
+            type Output = Self;
+            fn add(self, val: $t) -> Self{
+                Self {
+                    col1: self.col1.add(val),
+                    col2: self.col2.add(val),
+                }
+            }
         }
-    }
-    
-    pub const I: Self = Self::from_cols(Vec2::I, Vec2::J);
 
-    pub const ZERO: Self = Self::from_cols(Vec2::ZERO, Vec2::ZERO);
-}
+        impl Sub<$Mat2> for $Mat2 {
+            type Output = Self;
+            fn sub(self, mat: Self) -> Self {
+                Self {
+                    col1: self.col1.sub(mat.col1),
+                    col2: self.col2.sub(mat.col2),
+                }
+            }
+        }
 
-impl Add<Mat2> for Mat2 {
-    type Output = Self;
-    fn add(self, mat: Self) -> Self{
-        Self {
-            col1: self.col1.add(mat.col1),
-            col2: self.col2.add(mat.col2),
+        impl Sub<$t> for $Mat2 {
+            type Output = Self;
+            fn sub(self, val: $t) -> Self{
+                Self {
+                    col1: self.col1.sub(val),
+                    col2: self.col2.sub(val),
+                }
+            }
         }
-    }
-}
 
-impl Add<f32> for Mat2 {
-    type Output = Self;
-    fn add(self, val: f32) -> Self{
-        Self {
-            col1: self.col1.add(val),
-            col2: self.col2.add(val),
+        impl Mul<$t> for $Mat2 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    col1: self.col1.mul(val),
+                    col2: self.col2.mul(val),
+                }
+            }
         }
-    }
-}
 
-impl Sub<Mat2> for Mat2 {
-    type Output = Self;
-    fn sub(self, mat: Self) -> Self {
-        Self {
-            col1: self.col1.sub(mat.col1),
-            col2: self.col2.sub(mat.col2),
+        impl Div<$t> for $Mat2 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    col1: self.col1.div(val),
+                    col2: self.col2.div(val),
+                }
+            }
         }
-    }
-}
 
-impl Sub<f32> for Mat2 {
-    type Output = Self;
-    fn sub(self, val: f32) -> Self{
-        Self {
-            col1: self.col1.sub(val),
-            col2: self.col2.sub(val),
+        impl Neg for $Mat2 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    col1: -self.col1,
+                    col2: -self.col2,
+                }
+            }
         }
-    }
+
+        impl Mul<$Vec2> for $Mat2 {
+            type Output = $Vec2;
+            fn mul(self, vec: $Vec2) -> $Vec2 {
+                let row1: $Vec2 = $Vec2::new(self.col1.i, self.col2.i);
+                let row2: $Vec2 = $Vec2::new(self.col1.j, self.col2.j);
+
+                $Vec2::new(row1 * vec, row2 * vec)
+            }
+        }
+
+        impl Mul<$Mat2> for $Mat2 {
+            type Output = Self;
+            fn mul(self, mat: $Mat2) -> $Mat2 {
+                Self {
+                    col1: self * mat.col1,
+                    col2: self * mat.col2,
+                }
+            }
+        }
+
+        // Indexes the columns
+        impl Index<usize> for $Mat2 {
+            type Output = $Vec2;
+            fn index(&self, index: usize) -> &$Vec2 {
+                match index {
+                    0 => &self.col1,
+                    1 => &self.col2,
+                    _ => panic!("index out of bounds: the len is 2 but the index is {}", index),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $Mat2 {
+            fn index_mut(&mut self, index: usize) -> &mut $Vec2 {
+                match index {
+                    0 => &mut self.col1,
+                    1 => &mut self.col2,
+                    _ => panic!("index out of bounds: the len is 2 but the index is {}", index),
+                }
+            }
+        }
+
+        impl_ref_ops!($Mat2;
+            Add<$Mat2>, add;
+            Add<$t>, add;
+            Sub<$Mat2>, sub;
+            Sub<$t>, sub;
+            Mul<$Vec2>, mul;
+            Mul<$Mat2>, mul;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Mat2;
+            AddAssign<$Mat2>, add_assign, Add, add;
+            AddAssign<$t>, add_assign, Add, add;
+            SubAssign<$Mat2>, sub_assign, Sub, sub;
+            SubAssign<$t>, sub_assign, Sub, sub;
+            MulAssign<$Mat2>, mul_assign, Mul, mul;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Mat2, $t);
+        impl_ref_neg!($Mat2);
+    };
 }
 
-impl Mul<Vec2> for Mat2 {
-    type Output = Vec2;
-    fn mul(self, vec: Vec2) -> Vec2 {
-        let row1: Vec2 = Vec2::new(self.col1.i, self.col2.i);
-        let row2: Vec2 = Vec2::new(self.col1.j, self.col2.j);
-        
-        Vec2::new(row1 * vec, row2 * vec)
+impl_mat2!(Mat2, f32, Vec2, Rot2);
+impl_mat2!(DMat2, f64, DVec2, DRot2);
+
+impl Mat2 {
+    pub fn as_f64(self) -> DMat2 {
+        DMat2::from_cols(self.col1.as_f64(), self.col2.as_f64())
     }
 }
 
-impl Mul<Mat2> for Mat2 {
-    type Output = Self;
-    fn mul(self, mat: Mat2) -> Mat2 {
-        Self {
-            col1: self * mat.col1,
-            col2: self * mat.col2,
-        }
+impl DMat2 {
+    pub fn as_f32(self) -> Mat2 {
+        Mat2::from_cols(self.col1.as_f32(), self.col2.as_f32())
     }
 }
-
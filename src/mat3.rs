@@ -1,137 +1,343 @@
-use crate::{Vec2, Vec3, Vec4, Mat2, Mat4, Bivec3};
+use crate::{math, EulerOrder, Vec2, Vec3, Mat4, Rot2, Rot, DVec2, DVec3, DMat4, DRot2, DRot};
+use crate::impl_ops::*;
 use core::{f32, ops::*};
 
-#[derive(Debug, Clone, Copy)]
-pub struct Mat3 {
-    pub col1: Vec3,
-    pub col2: Vec3,
-    pub col3: Vec3,
-}
+macro_rules! impl_mat3 {
+    ($Mat3:ident, $t:ident, $Vec2:ident, $Vec3:ident, $Mat4:ident, $Rot2:ident, $Rot:ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(C)]
+        pub struct $Mat3 {
+            pub col1: $Vec3,
+            pub col2: $Vec3,
+            pub col3: $Vec3,
+        }
 
-impl Mat3 {
-    pub const I: Self = Self::from_cols(Vec3::I, Vec3::J, Vec3::K);
-
-    pub const ZERO: Self = Self::from_cols(Vec3::ZERO, Vec3::ZERO, Vec3::ZERO);
-
-    const fn new(
-        m00: f32, m01: f32, m02: f32,
-        m10: f32, m11: f32, m12: f32,
-        m20: f32, m21: f32, m22: f32,
-    ) -> Self {
-        Self {
-            col1: Vec3::new(m00, m10, m20),
-            col2: Vec3::new(m01, m11, m21),
-            col3: Vec3::new(m02, m12, m22),
+        impl $Mat3 {
+            pub const I: Self = Self::from_cols($Vec3::I, $Vec3::J, $Vec3::K);
+
+            pub const ZERO: Self = Self::from_cols($Vec3::ZERO, $Vec3::ZERO, $Vec3::ZERO);
+
+            #[allow(dead_code, clippy::too_many_arguments)]
+            const fn new(
+                m00: $t, m01: $t, m02: $t,
+                m10: $t, m11: $t, m12: $t,
+                m20: $t, m21: $t, m22: $t,
+            ) -> Self {
+                Self {
+                    col1: $Vec3::new(m00, m10, m20),
+                    col2: $Vec3::new(m01, m11, m21),
+                    col3: $Vec3::new(m02, m12, m22),
+                }
+            }
+
+            pub const fn from_cols(vec1: $Vec3, vec2: $Vec3, vec3: $Vec3) -> Self {
+                Self {
+                    col1: vec1,
+                    col2: vec2,
+                    col3: vec3,
+                }
+            }
+
+            // Column major
+            pub const fn from_cols_array(array: &[$t; 9]) -> Self {
+                Self::from_cols(
+                    $Vec3::new(array[0], array[1], array[2]),
+                    $Vec3::new(array[3], array[4], array[5]),
+                    $Vec3::new(array[6], array[7], array[8]),
+                )
+            }
+
+            // Column major
+            pub const fn to_cols_array(self) -> [$t; 9] {
+                [
+                    self.col1.i, self.col1.j, self.col1.k,
+                    self.col2.i, self.col2.j, self.col2.k,
+                    self.col3.i, self.col3.j, self.col3.k,
+                ]
+            }
+
+            pub fn abs_diff_eq(self, other: $Mat3, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_cols_array(), &other.to_cols_array(), max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Mat3, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_cols_array(), &other.to_cols_array(), max_abs_diff, max_relative)
+            }
+
+            // Not recommended, this is slow
+            pub const fn from_rows(row1: $Vec3, row2: $Vec3, row3: $Vec3) -> Self {
+                Self {
+                    col1: $Vec3::new(row1.i, row2.i, row3.i),
+                    col2: $Vec3::new(row1.j, row2.j, row3.j),
+                    col3: $Vec3::new(row1.k, row2.k, row3.k),
+                }
+            }
+
+            pub const fn from_scale(scale: $Vec2) -> Self {
+                Self::from_cols(
+                    $Vec3::new(scale.i, 0.0, 0.0),
+                    $Vec3::new(0.0, scale.j, 0.0),
+                    $Vec3::K,
+                )
+            }
+
+            pub const fn from_translation(translation: $Vec2) -> Self {
+                Self::from_cols(
+                    $Vec3::I,
+                    $Vec3::J,
+                    $Vec3::new(translation.i, translation.j, 1.0)
+                )
+            }
+
+            pub fn from_rotation(angle: $t) -> Self {
+                let sin: $t = math::$t::sin(angle);
+                let cos: $t = math::$t::cos(angle);
+                Self::from_cols(
+                    $Vec3::new(cos, sin, 0.0),
+                    $Vec3::new(- sin, cos, 0.0),
+                    $Vec3::K,
+                )
+            }
+
+            // Upper left 3x3 block, e.g. for building a normal matrix
+            pub const fn from_mat4(mat: $Mat4) -> Self {
+                Self::from_cols(
+                    $Vec3::new(mat.col1.i, mat.col1.j, mat.col1.k),
+                    $Vec3::new(mat.col2.i, mat.col2.j, mat.col2.k),
+                    $Vec3::new(mat.col3.i, mat.col3.j, mat.col3.k),
+                )
+            }
+
+            // Same as Rot::from_euler, see EulerOrder
+            pub fn from_euler(order: EulerOrder, a: $t, b: $t, c: $t) -> Self {
+                Self::from_rot($Rot::from_euler(order, a, b, c))
+            }
+
+            pub fn from_rot(rot: $Rot) -> Self {
+                Self::from_cols(
+                    rot.rotate($Vec3::I),
+                    rot.rotate($Vec3::J),
+                    rot.rotate($Vec3::K),
+                )
+            }
+
+            // 2D rotation in homogeneous coordinates, like Mat3::from_rotation
+            pub fn from_rot2(rot: $Rot2) -> Self {
+                let i: $Vec2 = rot.rotate($Vec2::I);
+                let j: $Vec2 = rot.rotate($Vec2::J);
+                Self::from_cols(
+                    $Vec3::new(i.i, i.j, 0.0),
+                    $Vec3::new(j.i, j.j, 0.0),
+                    $Vec3::K,
+                )
+            }
+
+            // Component-wise product
+            pub fn mul_elem(self, other: $Mat3) -> Self {
+                Self {
+                    col1: self.col1.mul_elem(other.col1),
+                    col2: self.col2.mul_elem(other.col2),
+                    col3: self.col3.mul_elem(other.col3),
+                }
+            }
+
+            // Component-wise quotient
+            pub fn div_elem(self, other: $Mat3) -> Self {
+                Self {
+                    col1: self.col1.div_elem(other.col1),
+                    col2: self.col2.div_elem(other.col2),
+                    col3: self.col3.div_elem(other.col3),
+                }
+            }
+
+            pub const fn transpose(self) -> Self {
+                Self::from_rows(self.col1, self.col2, self.col3)
+            }
+
+            pub fn determinant(self) -> $t {
+                self.col1 * self.col2.cross(self.col3)
+            }
+
+            // The rows of the inverse are the cross products of the columns
+            // be careful, divide by zero error!
+            pub fn inverse(self) -> Self {
+                let row1: $Vec3 = self.col2.cross(self.col3);
+                let row2: $Vec3 = self.col3.cross(self.col1);
+                let row3: $Vec3 = self.col1.cross(self.col2);
+                let det_inv: $t = 1.0 / (self.col1 * row1);
+                Self::from_rows(row1 * det_inv, row2 * det_inv, row3 * det_inv)
+            }
+
+            // Returns None if the matrix is singular
+            pub fn try_inverse(self) -> Option<Self> {
+                let det: $t = self.determinant();
+                if det == 0.0 || !det.is_finite() {
+                    return None;
+                }
+                Some(self.inverse())
+            }
         }
-    }
 
-    pub const fn from_cols(vec1: Vec3, vec2: Vec3, vec3: Vec3) -> Self {
-        Self {
-            col1: vec1,
-            col2: vec2,
-            col3: vec3,
+        impl Add<$Mat3> for $Mat3 {
+            type Output = Self;
+            fn add(self, mat: Self) -> Self{
+                Self {
+                    col1: self.col1.add(mat.col1),
+                    col2: self.col2.add(mat.col2),
+                    col3: self.col3.add(mat.col3),
+                }
+            }
         }
-    }
 
-    // Not recommended, this is slow
-    pub const fn from_rows(row1: Vec3, row2: Vec3, row3: Vec3) -> Self {
-        Self {
-            col1: Vec3::new(row1.i, row2.i, row3.i),
-            col2: Vec3::new(row1.j, row2.j, row3.j),
-            col3: Vec3::new(row1.k, row2.k, row3.k),
+        impl Add<$t> for $Mat3 {
+            type Output = Self;
+            fn add(self, val: $t) -> Self{
+                Self {
+                    col1: self.col1.add(val),
+                    col2: self.col2.add(val),
+                    col3: self.col3.add(val),
+                }
+            }
         }
-    }
-    
-    pub const fn from_scale(scale: Vec2) -> Self {
-        Self::from_cols(
-            Vec3::new(scale.i, 0.0, 0.0),
-            Vec3::new(0.0, scale.j, 0.0),
-            Vec3::K,
-        )
-    }
 
-    pub const fn from_translation(translation: Vec2) -> Self {
-        Self::from_cols(
-            Vec3::I,
-            Vec3::J,
-            Vec3::new(translation.i, translation.j, 1.0)
-        )
-    }
+        impl Sub<$Mat3> for $Mat3 {
+            type Output = Self;
+            fn sub(self, mat: Self) -> Self {
+                Self {
+                    col1: self.col1.sub(mat.col1),
+                    col2: self.col2.sub(mat.col2),
+                    col3: self.col3.sub(mat.col3),
+                }
+            }
+        }
 
-    pub fn from_rotation(angle: f32) -> Self {
-        let sin: f32 = f32::sin(angle);
-        let cos: f32 = f32::cos(angle);
-        Self::from_cols(
-            Vec3::new(cos, sin, 0.0),
-            Vec3::new(- sin, cos, 0.0),
-            Vec3::K,
-        )
-    }
-}
+        impl Sub<$t> for $Mat3 {
+            type Output = Self;
+            fn sub(self, val: $t) -> Self{
+                Self {
+                    col1: self.col1.sub(val),
+                    col2: self.col2.sub(val),
+                    col3: self.col3.sub(val),
+                }
+            }
+        }
 
-impl Add<Mat3> for Mat3 {
-    type Output = Self;
-    fn add(self, mat: Self) -> Self{
-        Self {
-            col1: self.col1.add(mat.col1),
-            col2: self.col2.add(mat.col2),
-            col3: self.col3.add(mat.col3),
+        impl Mul<$t> for $Mat3 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    col1: self.col1.mul(val),
+                    col2: self.col2.mul(val),
+                    col3: self.col3.mul(val),
+                }
+            }
         }
-    }
-}
 
-impl Add<f32> for Mat3 {
-    type Output = Self;
-    fn add(self, val: f32) -> Self{
-        Self {
-            col1: self.col1.add(val),
-            col2: self.col2.add(val),
-            col3: self.col3.add(val),
+        impl Div<$t> for $Mat3 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    col1: self.col1.div(val),
+                    col2: self.col2.div(val),
+                    col3: self.col3.div(val),
+                }
+            }
         }
-    }
-}
 
-impl Sub<Mat3> for Mat3 {
-    type Output = Self;
-    fn sub(self, mat: Self) -> Self {
-        Self {
-            col1: self.col1.sub(mat.col1),
-            col2: self.col2.sub(mat.col2),
-            col3: self.col3.sub(mat.col3),
+        impl Neg for $Mat3 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    col1: -self.col1,
+                    col2: -self.col2,
+                    col3: -self.col3,
+                }
+            }
         }
-    }
-}
 
-impl Sub<f32> for Mat3 {
-    type Output = Self;
-    fn sub(self, val: f32) -> Self{
-        Self {
-            col1: self.col1.sub(val),
-            col2: self.col2.sub(val),
-            col3: self.col3.sub(val),
+        impl Mul<$Vec3> for $Mat3 {
+            type Output = $Vec3;
+            fn mul(self, vec: $Vec3) -> $Vec3 {
+                let row1: $Vec3 = $Vec3::new(self.col1.i, self.col2.i, self.col3.i);
+                let row2: $Vec3 = $Vec3::new(self.col1.j, self.col2.j, self.col3.j);
+                let row3: $Vec3 = $Vec3::new(self.col1.k, self.col2.k, self.col3.k);
+
+                $Vec3::new(row1 * vec, row2 * vec, row3 * vec)
+            }
         }
-    }
+
+        impl Mul<$Mat3> for $Mat3 {
+            type Output = Self;
+            fn mul(self, mat: $Mat3) -> $Mat3 {
+                Self {
+                    col1: self * mat.col1,
+                    col2: self * mat.col2,
+                    col3: self * mat.col3,
+                }
+            }
+        }
+
+        // Indexes the columns
+        impl Index<usize> for $Mat3 {
+            type Output = $Vec3;
+            fn index(&self, index: usize) -> &$Vec3 {
+                match index {
+                    0 => &self.col1,
+                    1 => &self.col2,
+                    2 => &self.col3,
+                    _ => panic!("index out of bounds: the len is 3 but the index is {}", index),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $Mat3 {
+            fn index_mut(&mut self, index: usize) -> &mut $Vec3 {
+                match index {
+                    0 => &mut self.col1,
+                    1 => &mut self.col2,
+                    2 => &mut self.col3,
+                    _ => panic!("index out of bounds: the len is 3 but the index is {}", index),
+                }
+            }
+        }
+
+        impl_ref_ops!($Mat3;
+            Add<$Mat3>, add;
+            Add<$t>, add;
+            Sub<$Mat3>, sub;
+            Sub<$t>, sub;
+            Mul<$Vec3>, mul;
+            Mul<$Mat3>, mul;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Mat3;
+            AddAssign<$Mat3>, add_assign, Add, add;
+            AddAssign<$t>, add_assign, Add, add;
+            SubAssign<$Mat3>, sub_assign, Sub, sub;
+            SubAssign<$t>, sub_assign, Sub, sub;
+            MulAssign<$Mat3>, mul_assign, Mul, mul;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Mat3, $t);
+        impl_ref_neg!($Mat3);
+    };
 }
 
-impl Mul<Vec3> for Mat3 {
-    type Output = Vec3;
-    fn mul(self, vec: Vec3) -> Vec3 {
-        let row1: Vec3 = Vec3::new(self.col1.i, self.col2.i, self.col3.i);
-        let row2: Vec3 = Vec3::new(self.col1.j, self.col2.j, self.col3.j);
-        let row3: Vec3 = Vec3::new(self.col1.k, self.col2.k, self.col3.k);
-        
-        Vec3::new(row1 * vec, row2 * vec, row3 * vec)
+impl_mat3!(Mat3, f32, Vec2, Vec3, Mat4, Rot2, Rot);
+impl_mat3!(DMat3, f64, DVec2, DVec3, DMat4, DRot2, DRot);
+
+impl Mat3 {
+    pub fn as_f64(self) -> DMat3 {
+        DMat3::from_cols(self.col1.as_f64(), self.col2.as_f64(), self.col3.as_f64())
     }
 }
 
-impl Mul<Mat3> for Mat3 {
-    type Output = Self;
-    fn mul(self, mat: Mat3) -> Mat3 {
-        Self {
-            col1: self * mat.col1,
-            col2: self * mat.col2,
-            col3: self * mat.col3,
-        }
+impl DMat3 {
+    pub fn as_f32(self) -> Mat3 {
+        Mat3::from_cols(self.col1.as_f32(), self.col2.as_f32(), self.col3.as_f32())
     }
 }
-
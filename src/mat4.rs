@@ -1,252 +1,595 @@
-use crate::{Vec2, Vec3, Vec4, Mat2, Mat3, Bivec3};
+use crate::{math, EulerOrder, Vec3, Vec4, Mat3, Rot, Rot4, DVec3, DVec4, DMat3, DRot, DRot4};
+use crate::impl_ops::*;
 use core::{f32, ops::*};
 
-// const fn mat4(col1: Vec4, col2: Vec4, col3: Vec4, col4: Vec4) -> Mat4 {
-//     Mat4::new(col1, col2, col3, col4)
-// }
+macro_rules! impl_mat4 {
+    ($Mat4:ident, $t:ident, $Vec3:ident, $Vec4:ident, $Mat3:ident, $Rot:ident, $Rot4:ident) => {
+        // const fn mat4(col1: Vec4, col2: Vec4, col3: Vec4, col4: Vec4) -> Mat4 {
+        //     Mat4::new(col1, col2, col3, col4)
+        // }
 
-#[derive(Debug, Clone, Copy)]
-pub struct Mat4 {
-    pub col1: Vec4,
-    pub col2: Vec4,
-    pub col3: Vec4,
-    pub col4: Vec4,
-}
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(C)]
+        pub struct $Mat4 {
+            pub col1: $Vec4,
+            pub col2: $Vec4,
+            pub col3: $Vec4,
+            pub col4: $Vec4,
+        }
 
-impl Mat4 {
-    const fn new(
-        m00: f32, m01: f32, m02: f32, m03: f32,
-        m10: f32, m11: f32, m12: f32, m13: f32,
-        m20: f32, m21: f32, m22: f32, m23: f32,
-        m30: f32, m31: f32, m32: f32, m33: f32,
-    ) -> Self {
-        Self {
-            col1: Vec4::new(m00, m10, m20, m30),
-            col2: Vec4::new(m01, m11, m21, m31),
-            col3: Vec4::new(m02, m12, m22, m32),
-            col4: Vec4::new(m03, m13, m23, m33),
+        impl $Mat4 {
+            #[allow(clippy::too_many_arguments)]
+            const fn new(
+                m00: $t, m01: $t, m02: $t, m03: $t,
+                m10: $t, m11: $t, m12: $t, m13: $t,
+                m20: $t, m21: $t, m22: $t, m23: $t,
+                m30: $t, m31: $t, m32: $t, m33: $t,
+            ) -> Self {
+                Self {
+                    col1: $Vec4::new(m00, m10, m20, m30),
+                    col2: $Vec4::new(m01, m11, m21, m31),
+                    col3: $Vec4::new(m02, m12, m22, m32),
+                    col4: $Vec4::new(m03, m13, m23, m33),
+                }
+            }
+
+            pub const fn from_cols(vec1: $Vec4, vec2: $Vec4, vec3: $Vec4, vec4: $Vec4) -> Self {
+                Self {
+                    col1: vec1,
+                    col2: vec2,
+                    col3: vec3,
+                    col4: vec4,
+                }
+            }
+
+            // Column major
+            pub const fn from_cols_array(array: &[$t; 16]) -> Self {
+                Self::from_cols(
+                    $Vec4::new(array[0], array[1], array[2], array[3]),
+                    $Vec4::new(array[4], array[5], array[6], array[7]),
+                    $Vec4::new(array[8], array[9], array[10], array[11]),
+                    $Vec4::new(array[12], array[13], array[14], array[15]),
+                )
+            }
+
+            // Column major
+            pub const fn to_cols_array(self) -> [$t; 16] {
+                [
+                    self.col1.i, self.col1.j, self.col1.k, self.col1.l,
+                    self.col2.i, self.col2.j, self.col2.k, self.col2.l,
+                    self.col3.i, self.col3.j, self.col3.k, self.col3.l,
+                    self.col4.i, self.col4.j, self.col4.k, self.col4.l,
+                ]
+            }
+
+            pub fn abs_diff_eq(self, other: $Mat4, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_cols_array(), &other.to_cols_array(), max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Mat4, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_cols_array(), &other.to_cols_array(), max_abs_diff, max_relative)
+            }
+
+            // Not recommended, this is slow
+            pub const fn from_rows(row1: $Vec4, row2: $Vec4, row3: $Vec4, row4: $Vec4,) -> Self {
+                Self {
+                    col1: $Vec4::new(row1.i, row2.i, row3.i, row4.i),
+                    col2: $Vec4::new(row1.j, row2.j, row3.j, row4.j),
+                    col3: $Vec4::new(row1.k, row2.k, row3.k, row4.k),
+                    col4: $Vec4::new(row1.l, row2.l, row3.l, row4.l),
+                }
+            }
+
+            pub const fn from_scale(scale: $Vec3) -> Self {
+                Self::from_cols(
+                    $Vec4::new(scale.i, 0.0, 0.0, 0.0),
+                    $Vec4::new(0.0, scale.j, 0.0, 0.0),
+                    $Vec4::new(0.0, 0.0, scale.k, 0.0),
+                    $Vec4::L,
+                )
+            }
+
+            pub const fn from_translation(translation: $Vec3) -> Self {
+                Self::from_cols(
+                    $Vec4::I,
+                    $Vec4::J,
+                    $Vec4::K,
+                    $Vec4::new(translation.i, translation.j, translation.k, 1.0),
+                )
+            }
+
+            // Turns J towards K
+            pub fn from_rotation_i(angle: $t) -> Self {
+                let sin: $t = math::$t::sin(angle);
+                let cos: $t = math::$t::cos(angle);
+                Self::from_cols(
+                    $Vec4::I,
+                    $Vec4::new(0.0, cos, sin, 0.0),
+                    $Vec4::new(0.0, -sin, cos, 0.0),
+                    $Vec4::L,
+                )
+            }
+
+            // Turns K towards I
+            pub fn from_rotation_j(angle: $t) -> Self {
+                let sin: $t = math::$t::sin(angle);
+                let cos: $t = math::$t::cos(angle);
+                Self::from_cols(
+                    $Vec4::new(cos, 0.0, -sin, 0.0),
+                    $Vec4::J,
+                    $Vec4::new(sin, 0.0, cos, 0.0),
+                    $Vec4::L,
+                )
+            }
+
+            // Turns I towards J
+            pub fn from_rotation_k(angle: $t) -> Self {
+                let sin: $t = math::$t::sin(angle);
+                let cos: $t = math::$t::cos(angle);
+                Self::from_cols(
+                    $Vec4::new(cos, sin, 0.0, 0.0),
+                    $Vec4::new(-sin, cos, 0.0, 0.0),
+                    $Vec4::K,
+                    $Vec4::L,
+                )
+            }
+
+            pub const I: Self = Self::from_cols($Vec4::I, $Vec4::J, $Vec4::K, $Vec4::L);
+
+            pub const ZERO: Self = Self::from_cols($Vec4::ZERO, $Vec4::ZERO, $Vec4::ZERO, $Vec4::ZERO);
+
+            // Same as Rot::from_euler, see EulerOrder
+            pub fn from_euler(order: EulerOrder, a: $t, b: $t, c: $t) -> Self {
+                Self::from_rot($Rot::from_euler(order, a, b, c))
+            }
+
+            pub fn from_rot(rot: $Rot) -> Self {
+                let mat: $Mat3 = $Mat3::from_rot(rot);
+                Self::from_cols(
+                    $Vec4::new(mat.col1.i, mat.col1.j, mat.col1.k, 0.0),
+                    $Vec4::new(mat.col2.i, mat.col2.j, mat.col2.k, 0.0),
+                    $Vec4::new(mat.col3.i, mat.col3.j, mat.col3.k, 0.0),
+                    $Vec4::L,
+                )
+            }
+
+            // Rotation of 4D space, not a homogeneous transform like Mat4::from_rot
+            pub fn from_rot4(rot: $Rot4) -> Self {
+                Self::from_cols(
+                    rot.rotate($Vec4::I),
+                    rot.rotate($Vec4::J),
+                    rot.rotate($Vec4::K),
+                    rot.rotate($Vec4::L),
+                )
+            }
+
+            pub fn orthographic_left(l: $t, r: $t, b: $t, t: $t, n: $t, f: $t) -> Self {
+                let width_inv: $t = 1.0 / (r - l);
+                let height_inv: $t = 1.0 / (t - b);
+                let range_inv = 1.0 / (f - n);
+                Self::from_cols(
+                    $Vec4::new(2.0 * width_inv, 0.0, 0.0, 0.0),
+                    $Vec4::new(0.0, 2.0 * height_inv, 0.0, 0.0),
+                    $Vec4::new(0.0, 0.0, range_inv, 0.0),
+                    $Vec4::new(-(r + l) * width_inv, -(t + b) * height_inv, -range_inv * n, 1.0),
+                )
+            }   
+
+            pub fn orthographic_right(l: $t, r: $t, b: $t, t: $t, n: $t, f: $t) -> Self {
+                let width_inv: $t = 1.0 / (r - l);
+                let height_inv: $t = 1.0 / (t - b);
+                let range_inv = 1.0 / (n - f);
+                Self::from_cols(
+                    $Vec4::new(2.0 * width_inv, 0.0, 0.0, 0.0),
+                    $Vec4::new(0.0, 2.0 * height_inv, 0.0, 0.0),
+                    $Vec4::new(0.0, 0.0, range_inv, 0.0),
+                    $Vec4::new(-(r + l) * width_inv, -(t + b) * height_inv, -range_inv * n, 1.0),
+                )
+            }      
+
+            pub fn perspective_left(fov: $t, aspect_ratio: $t, near: $t, far: $t) -> Self {
+                let w: $t = 1.0 / math::$t::tan(fov * 0.5);
+                let h: $t = w * aspect_ratio;
+                let r: $t = far / (far - near);
+                Self::from_cols(
+                    $Vec4::new(w, 0.0, 0.0, 0.0),
+                    $Vec4::new(0.0, h, 0.0, 0.0),
+                    $Vec4::new(0.0, 0.0, r, 1.0),
+                    $Vec4::new(0.0, 0.0, -r * near, 0.0),
+                )
+            }
+
+            pub fn perspective_right(fov: $t, aspect_ratio: $t, near: $t, far: $t) -> Self {
+                let w: $t = 1.0 / math::$t::tan(fov * 0.5);
+                let h: $t = w * aspect_ratio;
+                let r: $t = far / (far - near);
+                Self::from_cols(
+                    $Vec4::new(w, 0.0, 0.0, 0.0),
+                    $Vec4::new(0.0, h, 0.0, 0.0),
+                    $Vec4::new(0.0, 0.0, r, -1.0),
+                    $Vec4::new(0.0, 0.0, r * near, 0.0),
+                )
+            }
+
+            pub fn perspective_inf_left(fov: $t, aspect_ratio: $t, near: $t) -> Self {
+                let w: $t = 1.0 / math::$t::tan(fov * 0.5);
+                let h: $t = w * aspect_ratio;
+                Self::from_cols(
+                    $Vec4::new(w, 0.0, 0.0, 0.0),
+                    $Vec4::new(0.0, h, 0.0, 0.0),
+                    $Vec4::new(0.0, 0.0, 1.0, 1.0),
+                    $Vec4::new(0.0, 0.0, -near, 0.0),
+                )
+            }
+
+            pub fn perspective_inf_right(fov: $t, aspect_ratio: $t, near: $t) -> Self {
+                let w: $t = 1.0 / math::$t::tan(fov * 0.5);
+                let h: $t = w * aspect_ratio;
+                Self::from_cols(
+                    $Vec4::new(w, 0.0, 0.0, 0.0),
+                    $Vec4::new(0.0, h, 0.0, 0.0),
+                    $Vec4::new(0.0, 0.0, -1.0, -1.0),
+                    $Vec4::new(0.0, 0.0, -near, 0.0),
+                )
+            }
+
+            // View matrix for a camera at eye facing dir, looking down +K like
+            // perspective_left
+            pub fn look_to_left(eye: $Vec3, dir: $Vec3, up: $Vec3) -> Self {
+                let f: $Vec3 = dir.normalize();
+                let s: $Vec3 = up.cross(f).normalize();
+                let u: $Vec3 = f.cross(s);
+                Self::from_cols(
+                    $Vec4::new(s.i, u.i, f.i, 0.0),
+                    $Vec4::new(s.j, u.j, f.j, 0.0),
+                    $Vec4::new(s.k, u.k, f.k, 0.0),
+                    $Vec4::new(-(s * eye), -(u * eye), -(f * eye), 1.0),
+                )
+            }
+
+            // View matrix for a camera at eye facing dir, looking down -K like
+            // perspective_right
+            pub fn look_to_right(eye: $Vec3, dir: $Vec3, up: $Vec3) -> Self {
+                Self::look_to_left(eye, -dir, up)
+            }
+
+            // Splits a matrix without shear or projection into its parts. A
+            // reflection is folded into the sign of the i scale.
+            pub fn to_scale_rot_translation(self) -> ($Vec3, $Rot, $Vec3) {
+                let mat: $Mat3 = $Mat3::from_mat4(self);
+                let sign: $t = if mat.determinant() < 0.0 { -1.0 } else { 1.0 };
+                let scale: $Vec3 = $Vec3::new(sign * mat.col1.norm(), mat.col2.norm(), mat.col3.norm());
+                let rot: $Rot = $Rot::from_mat3($Mat3::from_cols(
+                    mat.col1 / scale.i,
+                    mat.col2 / scale.j,
+                    mat.col3 / scale.k,
+                ));
+                let translation: $Vec3 = $Vec3::new(self.col4.i, self.col4.j, self.col4.k);
+                (scale, rot, translation)
+            }
+
+            pub fn look_at_left(eye: $Vec3, target: $Vec3, up: $Vec3) -> Self {
+                Self::look_to_left(eye, target - eye, up)
+            }
+
+            pub fn look_at_right(eye: $Vec3, target: $Vec3, up: $Vec3) -> Self {
+                Self::look_to_right(eye, target - eye, up)
+            }
+
+            // Component-wise product
+            pub fn mul_elem(self, other: $Mat4) -> Self {
+                Self {
+                    col1: self.col1.mul_elem(other.col1),
+                    col2: self.col2.mul_elem(other.col2),
+                    col3: self.col3.mul_elem(other.col3),
+                    col4: self.col4.mul_elem(other.col4),
+                }
+            }
+
+            // Component-wise quotient
+            pub fn div_elem(self, other: $Mat4) -> Self {
+                Self {
+                    col1: self.col1.div_elem(other.col1),
+                    col2: self.col2.div_elem(other.col2),
+                    col3: self.col3.div_elem(other.col3),
+                    col4: self.col4.div_elem(other.col4),
+                }
+            }
+
+            pub const fn transpose(self) -> Self {
+                Self::from_rows(self.col1, self.col2, self.col3, self.col4)
+            }
+
+            // 2x2 minors of the top two rows and the bottom two rows, shared between
+            // the determinant and the inverse
+            fn minors(self) -> ([$t; 6], [$t; 6]) {
+                let (a00, a10, a20, a30) = (self.col1.i, self.col1.j, self.col1.k, self.col1.l);
+                let (a01, a11, a21, a31) = (self.col2.i, self.col2.j, self.col2.k, self.col2.l);
+                let (a02, a12, a22, a32) = (self.col3.i, self.col3.j, self.col3.k, self.col3.l);
+                let (a03, a13, a23, a33) = (self.col4.i, self.col4.j, self.col4.k, self.col4.l);
+                (
+                    [
+                        a00 * a11 - a10 * a01,
+                        a00 * a12 - a10 * a02,
+                        a00 * a13 - a10 * a03,
+                        a01 * a12 - a11 * a02,
+                        a01 * a13 - a11 * a03,
+                        a02 * a13 - a12 * a03,
+                    ],
+                    [
+                        a20 * a31 - a30 * a21,
+                        a20 * a32 - a30 * a22,
+                        a20 * a33 - a30 * a23,
+                        a21 * a32 - a31 * a22,
+                        a21 * a33 - a31 * a23,
+                        a22 * a33 - a32 * a23,
+                    ],
+                )
+            }
+
+            pub fn determinant(self) -> $t {
+                let (s, c) = self.minors();
+                s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+            }
+
+            // be careful, divide by zero error!
+            pub fn inverse(self) -> Self {
+                let (a00, a10, a20, a30) = (self.col1.i, self.col1.j, self.col1.k, self.col1.l);
+                let (a01, a11, a21, a31) = (self.col2.i, self.col2.j, self.col2.k, self.col2.l);
+                let (a02, a12, a22, a32) = (self.col3.i, self.col3.j, self.col3.k, self.col3.l);
+                let (a03, a13, a23, a33) = (self.col4.i, self.col4.j, self.col4.k, self.col4.l);
+                let (s, c) = self.minors();
+                let det_inv: $t = 1.0 / (s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]);
+
+                Self::new(
+                    ( a11 * c[5] - a12 * c[4] + a13 * c[3]) * det_inv,
+                    (-a01 * c[5] + a02 * c[4] - a03 * c[3]) * det_inv,
+                    ( a31 * s[5] - a32 * s[4] + a33 * s[3]) * det_inv,
+                    (-a21 * s[5] + a22 * s[4] - a23 * s[3]) * det_inv,
+
+                    (-a10 * c[5] + a12 * c[2] - a13 * c[1]) * det_inv,
+                    ( a00 * c[5] - a02 * c[2] + a03 * c[1]) * det_inv,
+                    (-a30 * s[5] + a32 * s[2] - a33 * s[1]) * det_inv,
+                    ( a20 * s[5] - a22 * s[2] + a23 * s[1]) * det_inv,
+
+                    ( a10 * c[4] - a11 * c[2] + a13 * c[0]) * det_inv,
+                    (-a00 * c[4] + a01 * c[2] - a03 * c[0]) * det_inv,
+                    ( a30 * s[4] - a31 * s[2] + a33 * s[0]) * det_inv,
+                    (-a20 * s[4] + a21 * s[2] - a23 * s[0]) * det_inv,
+
+                    (-a10 * c[3] + a11 * c[1] - a12 * c[0]) * det_inv,
+                    ( a00 * c[3] - a01 * c[1] + a02 * c[0]) * det_inv,
+                    (-a30 * s[3] + a31 * s[1] - a32 * s[0]) * det_inv,
+                    ( a20 * s[3] - a21 * s[1] + a22 * s[0]) * det_inv,
+                )
+            }
+
+            // Returns None if the matrix is singular
+            pub fn try_inverse(self) -> Option<Self> {
+                let det: $t = self.determinant();
+                if det == 0.0 || !det.is_finite() {
+                    return None;
+                }
+                Some(self.inverse())
+            }
+
+            // Faster inverse for matrices whose last row is (0, 0, 0, 1), only the
+            // upper 3x3 block gets inverted
+            // be careful, divide by zero error!
+            pub fn inverse_affine(self) -> Self {
+                let mat_inv: $Mat3 = $Mat3::from_mat4(self).inverse();
+                let translation: $Vec3 = mat_inv * $Vec3::new(self.col4.i, self.col4.j, self.col4.k);
+                Self::from_cols(
+                    $Vec4::new(mat_inv.col1.i, mat_inv.col1.j, mat_inv.col1.k, 0.0),
+                    $Vec4::new(mat_inv.col2.i, mat_inv.col2.j, mat_inv.col2.k, 0.0),
+                    $Vec4::new(mat_inv.col3.i, mat_inv.col3.j, mat_inv.col3.k, 0.0),
+                    $Vec4::new(-translation.i, -translation.j, -translation.k, 1.0),
+                )
+            }
+
+            // Returns None if the upper 3x3 block is singular
+            pub fn try_inverse_affine(self) -> Option<Self> {
+                let det: $t = $Mat3::from_mat4(self).determinant();
+                if det == 0.0 || !det.is_finite() {
+                    return None;
+                }
+                Some(self.inverse_affine())
+            }
         }
-    }
 
-    pub const fn from_cols(vec1: Vec4, vec2: Vec4, vec3: Vec4, vec4: Vec4) -> Self {
-        Self {
-            col1: vec1,
-            col2: vec2,
-            col3: vec3,
-            col4: vec4,
+        impl Add<$Mat4> for $Mat4 {
+            type Output = Self;
+            fn add(self, mat: Self) -> Self{
+                Self {
+                    col1: self.col1.add(mat.col1),
+                    col2: self.col2.add(mat.col2),
+                    col3: self.col3.add(mat.col3),
+                    col4: self.col4.add(mat.col4),
+                }
+            }
         }
-    }
 
-    // Not recommended, this is slow
-    pub const fn from_rows(row1: Vec4, row2: Vec4, row3: Vec4, row4: Vec4,) -> Self {
-        Self {
-            col1: Vec4::new(row1.i, row2.i, row3.i, row4.i),
-            col2: Vec4::new(row1.j, row2.j, row3.j, row4.j),
-            col3: Vec4::new(row1.k, row2.k, row3.k, row4.k),
-            col4: Vec4::new(row1.l, row2.l, row3.l, row4.l),
+        impl Add<$t> for $Mat4 {
+            type Output = Self;
+            fn add(self, val: $t) -> Self{
+                Self {
+                    col1: self.col1.add(val),
+                    col2: self.col2.add(val),
+                    col3: self.col3.add(val),
+                    col4: self.col4.add(val),
+                }
+            }
         }
-    }
 
-    pub const fn from_scale(scale: Vec3) -> Self {
-        Self::from_cols(
-            Vec4::new(scale.i, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, scale.j, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, scale.k, 0.0),
-            Vec4::L,
-        )
-    }
+        impl Sub<$Mat4> for $Mat4 {
+            type Output = Self;
+            fn sub(self, mat: Self) -> Self {
+                Self {
+                    col1: self.col1.sub(mat.col1),
+                    col2: self.col2.sub(mat.col2),
+                    col3: self.col3.sub(mat.col3),
+                    col4: self.col4.sub(mat.col4),
+                }
+            }
+        }
 
-    pub const fn from_translation(translation: Vec3) -> Self {
-        Self::from_cols(
-            Vec4::I,
-            Vec4::J,
-            Vec4::K,
-            Vec4::new(translation.i, translation.j, translation.k, 1.0),
-        )
-    }
+        impl Sub<$t> for $Mat4 {
+            type Output = Self;
+            fn sub(self, val: $t) -> Self{
+                Self {
+                    col1: self.col1.sub(val),
+                    col2: self.col2.sub(val),
+                    col3: self.col3.sub(val),
+                    col4: self.col4.sub(val),
+                }
+            }
+        }
+        impl Mul<$t> for $Mat4 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    col1: self.col1.mul(val),
+                    col2: self.col2.mul(val),
+                    col3: self.col3.mul(val),
+                    col4: self.col4.mul(val),
+                }
+            }
+        }
 
-    pub fn from_rotation_i(angle: f32) -> Self {
-        let sin: f32 = f32::sin(angle);
-        let cos: f32 = f32::cos(angle);
-        Self::from_cols(
-            Vec4::I,
-            Vec4::new(0.0, cos, sin, 0.0),
-            Vec4::new(0.0, -sin, cos, 0.0),
-            Vec4::L,
-        )
-    }
+        impl Div<$t> for $Mat4 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    col1: self.col1.div(val),
+                    col2: self.col2.div(val),
+                    col3: self.col3.div(val),
+                    col4: self.col4.div(val),
+                }
+            }
+        }
 
-    pub fn from_rotation_j(angle: f32) -> Self {
-        let sin: f32 = f32::sin(angle);
-        let cos: f32 = f32::cos(angle);
-        Self::from_cols(
-            Vec4::new(cos, 0.0, sin, 0.0),
-            Vec4::J,
-            Vec4::new(-sin, 0.0, cos, 0.0),
-            Vec4::L,
-        )
-    }
+        impl Neg for $Mat4 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    col1: -self.col1,
+                    col2: -self.col2,
+                    col3: -self.col3,
+                    col4: -self.col4,
+                }
+            }
+        }
 
-    pub fn from_rotation_k(angle: f32) -> Self {
-        let sin: f32 = f32::sin(angle);
-        let cos: f32 = f32::cos(angle);
-        Self::from_cols(
-            Vec4::new(cos, sin, 0.0, 0.0),
-            Vec4::new(-sin, cos, 0.0, 0.0),
-            Vec4::K,
-            Vec4::L,
-        )
-    }
-    
-    pub const I: Self = Self::from_cols(Vec4::I, Vec4::J, Vec4::K, Vec4::L);
-
-    pub const ZERO: Self = Self::from_cols(Vec4::ZERO, Vec4::ZERO, Vec4::ZERO, Vec4::ZERO);
-
-    // from_rot
-
-    pub fn orthographic_left(l: f32, r: f32, b: f32, t: f32, n: f32, f: f32) -> Self {
-        let width_inv: f32 = 1.0 / (r - l);
-        let height_inv: f32 = 1.0 / (t - b);
-        let range_inv = 1.0 / (f - n);
-        Self::from_cols(
-            Vec4::new(2.0 * width_inv, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, 2.0 * height_inv, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, range_inv, 0.0),
-            Vec4::new(-(r + l) * width_inv, -(t + b) * height_inv, -range_inv * n, 1.0),
-        )
-    }   
-
-    pub fn orthographic_right(l: f32, r: f32, b: f32, t: f32, n: f32, f: f32) -> Self {
-        let width_inv: f32 = 1.0 / (r - l);
-        let height_inv: f32 = 1.0 / (t - b);
-        let range_inv = 1.0 / (n - f);
-        Self::from_cols(
-            Vec4::new(2.0 * width_inv, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, 2.0 * height_inv, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, range_inv, 0.0),
-            Vec4::new(-(r + l) * width_inv, -(t + b) * height_inv, -range_inv * n, 1.0),
-        )
-    }      
-
-    pub fn perspective_left(fov: f32, aspect_ratio: f32, near: f32, far: f32) -> Self {
-        let w: f32 = 1.0 / f32::tan(fov * 0.5);
-        let h: f32 = w * aspect_ratio;
-        let r: f32 = far / (far - near);
-        Self::from_cols(
-            Vec4::new(w, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, h, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, r, 1.0),
-            Vec4::new(0.0, 0.0, -r * near, 0.0),
-        )
-    }
 
-    pub fn perspective_right(fov: f32, aspect_ratio: f32, near: f32, far: f32) -> Self {
-        let w: f32 = 1.0 / f32::tan(fov * 0.5);
-        let h: f32 = w * aspect_ratio;
-        let r: f32 = far / (far - near);
-        Self::from_cols(
-            Vec4::new(w, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, h, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, r, -1.0),
-            Vec4::new(0.0, 0.0, r * near, 0.0),
-        )
-    }
+        // Indexes the columns
+        impl Index<usize> for $Mat4 {
+            type Output = $Vec4;
+            fn index(&self, index: usize) -> &$Vec4 {
+                match index {
+                    0 => &self.col1,
+                    1 => &self.col2,
+                    2 => &self.col3,
+                    3 => &self.col4,
+                    _ => panic!("index out of bounds: the len is 4 but the index is {}", index),
+                }
+            }
+        }
 
-    pub fn perspective_inf_left(fov: f32, aspect_ratio: f32, near: f32) -> Self {
-        let w: f32 = 1.0 / f32::tan(fov * 0.5);
-        let h: f32 = w * aspect_ratio;
-        Self::from_cols(
-            Vec4::new(w, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, h, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, 1.0, 1.0),
-            Vec4::new(0.0, 0.0, -near, 0.0),
-        )
-    }
+        impl IndexMut<usize> for $Mat4 {
+            fn index_mut(&mut self, index: usize) -> &mut $Vec4 {
+                match index {
+                    0 => &mut self.col1,
+                    1 => &mut self.col2,
+                    2 => &mut self.col3,
+                    3 => &mut self.col4,
+                    _ => panic!("index out of bounds: the len is 4 but the index is {}", index),
+                }
+            }
+        }
 
-    pub fn perspective_inf_right(fov: f32, aspect_ratio: f32, near: f32) -> Self {
-        let w: f32 = 1.0 / f32::tan(fov * 0.5);
-        let h: f32 = w * aspect_ratio;
-        Self::from_cols(
-            Vec4::new(w, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, h, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, -1.0, -1.0),
-            Vec4::new(0.0, 0.0, -near, 0.0),
-        )
-    }
-}
+        impl_ref_ops!($Mat4;
+            Add<$Mat4>, add;
+            Add<$t>, add;
+            Sub<$Mat4>, sub;
+            Sub<$t>, sub;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
 
-impl Add<Mat4> for Mat4 {
-    type Output = Self;
-    fn add(self, mat: Self) -> Self{
-        Self {
-            col1: self.col1.add(mat.col1),
-            col2: self.col2.add(mat.col2),
-            col3: self.col3.add(mat.col3),
-            col4: self.col4.add(mat.col4),
-        }
-    }
-}
+        impl_assign_ops!($Mat4;
+            AddAssign<$Mat4>, add_assign, Add, add;
+            AddAssign<$t>, add_assign, Add, add;
+            SubAssign<$Mat4>, sub_assign, Sub, sub;
+            SubAssign<$t>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
 
-impl Add<f32> for Mat4 {
-    type Output = Self;
-    fn add(self, val: f32) -> Self{
-        Self {
-            col1: self.col1.add(val),
-            col2: self.col2.add(val),
-            col3: self.col3.add(val),
-            col4: self.col4.add(val),
-        }
-    }
+        impl_scalar_lhs_mul!($Mat4, $t);
+        impl_ref_neg!($Mat4);
+    };
 }
 
-impl Sub<Mat4> for Mat4 {
-    type Output = Self;
-    fn sub(self, mat: Self) -> Self {
-        Self {
-            col1: self.col1.sub(mat.col1),
-            col2: self.col2.sub(mat.col2),
-            col3: self.col3.sub(mat.col3),
-            col4: self.col4.sub(mat.col4),
+impl_mat4!(Mat4, f32, Vec3, Vec4, Mat3, Rot, Rot4);
+impl_mat4!(DMat4, f64, DVec3, DVec4, DMat3, DRot, DRot4);
+
+// Each column of the product is a linear combination of the columns of self
+macro_rules! impl_mat4_mul {
+    ($Mat4:ident, $Vec4:ident) => {
+        impl Mul<$Vec4> for $Mat4 {
+            type Output = $Vec4;
+            fn mul(self, vec: $Vec4) -> $Vec4 {
+                self.col1 * vec.i + self.col2 * vec.j + self.col3 * vec.k + self.col4 * vec.l
+            }
         }
-    }
-}
 
-impl Sub<f32> for Mat4 {
-    type Output = Self;
-    fn sub(self, val: f32) -> Self{
-        Self {
-            col1: self.col1.sub(val),
-            col2: self.col2.sub(val),
-            col3: self.col3.sub(val),
-            col4: self.col4.sub(val),
+        impl Mul<$Mat4> for $Mat4 {
+            type Output = Self;
+            fn mul(self, mat: $Mat4) -> $Mat4 {
+                Self {
+                    col1: self * mat.col1,
+                    col2: self * mat.col2,
+                    col3: self * mat.col3,
+                    col4: self * mat.col4,
+                }
+            }
         }
-    }
+    };
 }
 
-// Make this faster, you are literally a fucking pure math major
-// Look into simd
+#[cfg(not(feature = "simd"))]
+impl_mat4_mul!(Mat4, Vec4);
+impl_mat4_mul!(DMat4, DVec4);
+
+#[cfg(feature = "simd")]
 impl Mul<Vec4> for Mat4 {
     type Output = Vec4;
     fn mul(self, vec: Vec4) -> Vec4 {
-        let row1: Vec4 = Vec4::new(self.col1.i, self.col2.i, self.col3.i, self.col4.i);
-        let row2: Vec4 = Vec4::new(self.col1.j, self.col2.j, self.col3.j, self.col4.j);
-        let row3: Vec4 = Vec4::new(self.col1.k, self.col2.k, self.col3.k, self.col4.k);
-        let row4: Vec4 = Vec4::new(self.col1.l, self.col2.l, self.col3.l, self.col4.l);
-        
-        Vec4::new(row1 * vec, row2 * vec, row3 * vec, row4 * vec)
+        crate::simd::mat4_mul_vec4(&self, vec)
     }
 }
 
-// Optimize all the matrix multiplication; this is too slow
+#[cfg(feature = "simd")]
 impl Mul<Mat4> for Mat4 {
     type Output = Self;
     fn mul(self, mat: Mat4) -> Mat4 {
-        Self {
-            col1: self * mat.col1,
-            col2: self * mat.col2,
-            col3: self * mat.col3,
-            col4: self * mat.col4,
-        }
+        crate::simd::mat4_mul_mat4(&self, &mat)
+    }
+}
+
+impl_ref_ops!(Mat4; Mul<Vec4>, mul; Mul<Mat4>, mul);
+impl_ref_ops!(DMat4; Mul<DVec4>, mul; Mul<DMat4>, mul);
+impl_assign_ops!(Mat4; MulAssign<Mat4>, mul_assign, Mul, mul);
+impl_assign_ops!(DMat4; MulAssign<DMat4>, mul_assign, Mul, mul);
+
+impl Mat4 {
+    pub fn as_f64(self) -> DMat4 {
+        DMat4::from_cols(self.col1.as_f64(), self.col2.as_f64(), self.col3.as_f64(), self.col4.as_f64())
     }
 }
 
+impl DMat4 {
+    pub fn as_f32(self) -> Mat4 {
+        Mat4::from_cols(self.col1.as_f32(), self.col2.as_f32(), self.col3.as_f32(), self.col4.as_f32())
+    }
+}
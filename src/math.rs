@@ -0,0 +1,120 @@
+// Float functions that live in std, with libm standing in for them in no_std
+// builds. std wins if both features are enabled.
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("rgla needs either the `std` or the `libm` feature");
+
+macro_rules! impl_math {
+    ($t:ident, $sqrt:ident, $sin:ident, $cos:ident, $tan:ident, $acos:ident, $atan2:ident, $floor:ident, $ceil:ident, $round:ident) => {
+        pub(crate) mod $t {
+            #[inline]
+            pub fn sqrt(x: $t) -> $t {
+                #[cfg(feature = "std")]
+                { x.sqrt() }
+                #[cfg(not(feature = "std"))]
+                { libm::$sqrt(x) }
+            }
+
+            #[inline]
+            pub fn sin(x: $t) -> $t {
+                #[cfg(feature = "std")]
+                { x.sin() }
+                #[cfg(not(feature = "std"))]
+                { libm::$sin(x) }
+            }
+
+            #[inline]
+            pub fn cos(x: $t) -> $t {
+                #[cfg(feature = "std")]
+                { x.cos() }
+                #[cfg(not(feature = "std"))]
+                { libm::$cos(x) }
+            }
+
+            #[inline]
+            pub fn tan(x: $t) -> $t {
+                #[cfg(feature = "std")]
+                { x.tan() }
+                #[cfg(not(feature = "std"))]
+                { libm::$tan(x) }
+            }
+
+            #[inline]
+            pub fn acos(x: $t) -> $t {
+                #[cfg(feature = "std")]
+                { x.acos() }
+                #[cfg(not(feature = "std"))]
+                { libm::$acos(x) }
+            }
+
+            #[inline]
+            pub fn atan2(y: $t, x: $t) -> $t {
+                #[cfg(feature = "std")]
+                { y.atan2(x) }
+                #[cfg(not(feature = "std"))]
+                { libm::$atan2(y, x) }
+            }
+
+            #[inline]
+            pub fn floor(x: $t) -> $t {
+                #[cfg(feature = "std")]
+                { x.floor() }
+                #[cfg(not(feature = "std"))]
+                { libm::$floor(x) }
+            }
+
+            #[inline]
+            pub fn ceil(x: $t) -> $t {
+                #[cfg(feature = "std")]
+                { x.ceil() }
+                #[cfg(not(feature = "std"))]
+                { libm::$ceil(x) }
+            }
+
+            #[inline]
+            pub fn round(x: $t) -> $t {
+                #[cfg(feature = "std")]
+                { x.round() }
+                #[cfg(not(feature = "std"))]
+                { libm::$round(x) }
+            }
+
+            // Compares component by component
+            pub fn abs_diff_eq(lhs: &[$t], rhs: &[$t], max_abs_diff: $t) -> bool {
+                lhs.iter().zip(rhs).all(|(a, b)| (a - b).abs() <= max_abs_diff)
+            }
+
+            // Compares component by component, relative to the larger of the two
+            pub fn relative_eq(lhs: &[$t], rhs: &[$t], max_abs_diff: $t, max_relative: $t) -> bool {
+                lhs.iter().zip(rhs).all(|(a, b)| {
+                    let diff: $t = (a - b).abs();
+                    diff <= max_abs_diff || diff <= max_relative * a.abs().max(b.abs())
+                })
+            }
+
+            // libm has no powi, square and multiply instead
+            #[inline]
+            pub fn powi(x: $t, n: i32) -> $t {
+                #[cfg(feature = "std")]
+                { x.powi(n) }
+                #[cfg(not(feature = "std"))]
+                {
+                    let mut base: $t = if n < 0 { 1.0 / x } else { x };
+                    let mut exp: u32 = n.unsigned_abs();
+                    let mut out: $t = 1.0;
+                    while exp > 0 {
+                        if exp & 1 == 1 {
+                            out *= base;
+                        }
+                        base *= base;
+                        exp >>= 1;
+                    }
+                    out
+                }
+            }
+        }
+    };
+}
+
+impl_math!(f32, sqrtf, sinf, cosf, tanf, acosf, atan2f, floorf, ceilf, roundf);
+impl_math!(f64, sqrt, sin, cos, tan, acos, atan2, floor, ceil, round);
@@ -0,0 +1,369 @@
+use crate::{math, Vec3, Bivec3, Rot, DVec3, DBivec3, DRot};
+use crate::impl_ops::*;
+use core::{f32, ops::*};
+
+// Multivector of the 3D geometric algebra, with a scalar, a vector, a bivector
+// and a trivector (pseudoscalar) part. Vec3, Bivec3 and Rot are the pieces of
+// it with only some grades and can be converted into it.
+//
+// The basis is I, J, K, then I ^ J, I ^ K, J ^ K like Bivec3, then I ^ J ^ K.
+
+macro_rules! impl_multivec3 {
+    ($Multivec3:ident, $t:ident, $Vec3:ident, $Bivec3:ident, $Rot:ident, $multivec3:ident) => {
+        #[allow(dead_code, clippy::too_many_arguments)]
+        const fn $multivec3(a: $t, i: $t, j: $t, k: $t, b01: $t, b02: $t, b12: $t, t012: $t) -> $Multivec3 {
+            $Multivec3::new(a, i, j, k, b01, b02, b12, t012)
+        }
+
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Multivec3 {
+            pub a: $t,
+            pub i: $t,
+            pub j: $t,
+            pub k: $t,
+            pub b01: $t,
+            pub b02: $t,
+            pub b12: $t,
+            pub t012: $t,
+        }
+
+        impl $Multivec3 {
+            #[allow(clippy::too_many_arguments)]
+            pub const fn new(a: $t, i: $t, j: $t, k: $t, b01: $t, b02: $t, b12: $t, t012: $t) -> Self {
+                Self { a, i, j, k, b01, b02, b12, t012 }
+            }
+
+            pub const fn from_array(array: [$t; 8]) -> Self {
+                Self::new(array[0], array[1], array[2], array[3], array[4], array[5], array[6], array[7])
+            }
+
+            pub const fn to_array(self) -> [$t; 8] {
+                [self.a, self.i, self.j, self.k, self.b01, self.b02, self.b12, self.t012]
+            }
+
+            pub fn as_array(&self) -> &[$t; 8] {
+                unsafe { &*(self as *const Self as *const [$t; 8]) }
+            }
+
+            pub fn abs_diff_eq(self, other: $Multivec3, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Multivec3, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+            }
+
+            pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+            pub const ONE: Self = Self::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+            // Unit pseudoscalar I ^ J ^ K, it squares to -1
+            pub const T012: Self = Self::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+
+            pub fn from_scalar(a: $t) -> Self {
+                Self { a, ..Self::ZERO }
+            }
+
+            pub fn from_pseudoscalar(t012: $t) -> Self {
+                Self { t012, ..Self::ZERO }
+            }
+
+            pub fn scalar(self) -> $t {
+                self.a
+            }
+
+            pub fn vector(self) -> $Vec3 {
+                $Vec3::new(self.i, self.j, self.k)
+            }
+
+            pub fn bivector(self) -> $Bivec3 {
+                $Bivec3::new(self.b01, self.b02, self.b12)
+            }
+
+            pub fn pseudoscalar(self) -> $t {
+                self.t012
+            }
+
+            // Scalar and bivector parts, not normalized
+            pub fn even(self) -> $Rot {
+                $Rot::new(self.a, self.b01, self.b02, self.b12)
+            }
+
+            // Keeps only the given grade, 0 to 3, anything higher gives ZERO
+            pub fn grade(self, grade: usize) -> Self {
+                match grade {
+                    0 => Self { a: self.a, ..Self::ZERO },
+                    1 => Self { i: self.i, j: self.j, k: self.k, ..Self::ZERO },
+                    2 => Self { b01: self.b01, b02: self.b02, b12: self.b12, ..Self::ZERO },
+                    3 => Self { t012: self.t012, ..Self::ZERO },
+                    _ => Self::ZERO,
+                }
+            }
+
+            // Squared norm of all the components, same as the scalar part of
+            // self * self.reverse()
+            pub fn norm_squared(self) -> $t {
+                self.to_array().iter().map(|x| x * x).sum()
+            }
+
+            pub fn norm(self) -> $t {
+                math::$t::sqrt(self.norm_squared())
+            }
+
+            // Flips the order of the vectors in every blade, so grades 2 and 3
+            // change sign
+            pub fn reverse(self) -> Self {
+                Self {
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b12: -self.b12,
+                    t012: -self.t012,
+                    ..self
+                }
+            }
+
+            // Negates the odd grades
+            pub fn involute(self) -> Self {
+                Self {
+                    i: -self.i,
+                    j: -self.j,
+                    k: -self.k,
+                    t012: -self.t012,
+                    ..self
+                }
+            }
+
+            // self * (I ^ J ^ K)^-1, matches Bivec3::dual on bivectors
+            pub fn dual(self) -> Self {
+                Self {
+                    a: self.t012,
+                    i: self.b12,
+                    j: -self.b02,
+                    k: self.b01,
+                    b01: -self.k,
+                    b02: self.j,
+                    b12: -self.i,
+                    t012: -self.a,
+                }
+            }
+
+            // Outer product
+            pub fn wedge(self, other: $Multivec3) -> Self {
+                let p: $Multivec3 = self;
+                let q: $Multivec3 = other;
+                Self {
+                    a: p.a * q.a,
+                    i: p.a * q.i + p.i * q.a,
+                    j: p.a * q.j + p.j * q.a,
+                    k: p.a * q.k + p.k * q.a,
+                    b01: p.a * q.b01 + p.i * q.j - p.j * q.i + p.b01 * q.a,
+                    b02: p.a * q.b02 + p.i * q.k - p.k * q.i + p.b02 * q.a,
+                    b12: p.a * q.b12 + p.j * q.k - p.k * q.j + p.b12 * q.a,
+                    t012: p.a * q.t012 + p.i * q.b12 - p.j * q.b02 + p.k * q.b01 + p.b01 * q.k - p.b02 * q.j + p.b12 * q.i + p.t012 * q.a,
+                }
+            }
+
+            // Left contraction of self onto other, so a vector inner a bivector
+            // is the same as Bivec3::inner
+            pub fn inner(self, other: $Multivec3) -> Self {
+                let p: $Multivec3 = self;
+                let q: $Multivec3 = other;
+                Self {
+                    a: p.a * q.a + p.i * q.i + p.j * q.j + p.k * q.k - p.b01 * q.b01 - p.b02 * q.b02 - p.b12 * q.b12 - p.t012 * q.t012,
+                    i: p.a * q.i - p.j * q.b01 - p.k * q.b02 - p.b12 * q.t012,
+                    j: p.a * q.j + p.i * q.b01 - p.k * q.b12 + p.b02 * q.t012,
+                    k: p.a * q.k + p.i * q.b02 + p.j * q.b12 - p.b01 * q.t012,
+                    b01: p.a * q.b01 + p.k * q.t012,
+                    b02: p.a * q.b02 - p.j * q.t012,
+                    b12: p.a * q.b12 + p.i * q.t012,
+                    t012: p.a * q.t012,
+                }
+            }
+        }
+
+        impl From<$Vec3> for $Multivec3 {
+            fn from(vec: $Vec3) -> Self {
+                Self { i: vec.i, j: vec.j, k: vec.k, ..Self::ZERO }
+            }
+        }
+
+        impl From<$Bivec3> for $Multivec3 {
+            fn from(bivec: $Bivec3) -> Self {
+                Self { b01: bivec.b01, b02: bivec.b02, b12: bivec.b12, ..Self::ZERO }
+            }
+        }
+
+        impl From<$Rot> for $Multivec3 {
+            fn from(rot: $Rot) -> Self {
+                Self { a: rot.a, b01: rot.b01, b02: rot.b02, b12: rot.b12, ..Self::ZERO }
+            }
+        }
+
+        impl Add<$Multivec3> for $Multivec3 {
+            type Output = Self;
+            fn add(self, val: Self) -> Self {
+                Self {
+                    a: self.a.add(val.a),
+                    i: self.i.add(val.i),
+                    j: self.j.add(val.j),
+                    k: self.k.add(val.k),
+                    b01: self.b01.add(val.b01),
+                    b02: self.b02.add(val.b02),
+                    b12: self.b12.add(val.b12),
+                    t012: self.t012.add(val.t012),
+                }
+            }
+        }
+
+        impl Sub<$Multivec3> for $Multivec3 {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self {
+                Self {
+                    a: self.a.sub(val.a),
+                    i: self.i.sub(val.i),
+                    j: self.j.sub(val.j),
+                    k: self.k.sub(val.k),
+                    b01: self.b01.sub(val.b01),
+                    b02: self.b02.sub(val.b02),
+                    b12: self.b12.sub(val.b12),
+                    t012: self.t012.sub(val.t012),
+                }
+            }
+        }
+
+        impl Neg for $Multivec3 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    a: -self.a,
+                    i: -self.i,
+                    j: -self.j,
+                    k: -self.k,
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b12: -self.b12,
+                    t012: -self.t012,
+                }
+            }
+        }
+
+        impl Mul<$t> for $Multivec3 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    a: self.a.mul(val),
+                    i: self.i.mul(val),
+                    j: self.j.mul(val),
+                    k: self.k.mul(val),
+                    b01: self.b01.mul(val),
+                    b02: self.b02.mul(val),
+                    b12: self.b12.mul(val),
+                    t012: self.t012.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Multivec3 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    a: self.a.div(val),
+                    i: self.i.div(val),
+                    j: self.j.div(val),
+                    k: self.k.div(val),
+                    b01: self.b01.div(val),
+                    b02: self.b02.div(val),
+                    b12: self.b12.div(val),
+                    t012: self.t012.div(val),
+                }
+            }
+        }
+
+        // Geometric product
+        impl Mul<$Multivec3> for $Multivec3 {
+            type Output = Self;
+            fn mul(self, val: Self) -> Self {
+                let p: $Multivec3 = self;
+                let q: $Multivec3 = val;
+                Self {
+                    a: p.a * q.a + p.i * q.i + p.j * q.j + p.k * q.k - p.b01 * q.b01 - p.b02 * q.b02 - p.b12 * q.b12 - p.t012 * q.t012,
+                    i: p.a * q.i + p.i * q.a - p.j * q.b01 - p.k * q.b02 + p.b01 * q.j + p.b02 * q.k - p.b12 * q.t012 - p.t012 * q.b12,
+                    j: p.a * q.j + p.i * q.b01 + p.j * q.a - p.k * q.b12 - p.b01 * q.i + p.b02 * q.t012 + p.b12 * q.k + p.t012 * q.b02,
+                    k: p.a * q.k + p.i * q.b02 + p.j * q.b12 + p.k * q.a - p.b01 * q.t012 - p.b02 * q.i - p.b12 * q.j - p.t012 * q.b01,
+                    b01: p.a * q.b01 + p.i * q.j - p.j * q.i + p.k * q.t012 + p.b01 * q.a - p.b02 * q.b12 + p.b12 * q.b02 + p.t012 * q.k,
+                    b02: p.a * q.b02 + p.i * q.k - p.j * q.t012 - p.k * q.i + p.b01 * q.b12 + p.b02 * q.a - p.b12 * q.b01 - p.t012 * q.j,
+                    b12: p.a * q.b12 + p.i * q.t012 + p.j * q.k - p.k * q.j - p.b01 * q.b02 + p.b02 * q.b01 + p.b12 * q.a + p.t012 * q.i,
+                    t012: p.a * q.t012 + p.i * q.b12 - p.j * q.b02 + p.k * q.b01 + p.b01 * q.k - p.b02 * q.j + p.b12 * q.i + p.t012 * q.a,
+                }
+            }
+        }
+
+        impl Index<usize> for $Multivec3 {
+            type Output = $t;
+            fn index(&self, index: usize) -> &$t {
+                match index {
+                    0 => &self.a,
+                    1 => &self.i,
+                    2 => &self.j,
+                    3 => &self.k,
+                    4 => &self.b01,
+                    5 => &self.b02,
+                    6 => &self.b12,
+                    7 => &self.t012,
+                    _ => panic!("index out of bounds: the len is 8 but the index is {}", index),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $Multivec3 {
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                match index {
+                    0 => &mut self.a,
+                    1 => &mut self.i,
+                    2 => &mut self.j,
+                    3 => &mut self.k,
+                    4 => &mut self.b01,
+                    5 => &mut self.b02,
+                    6 => &mut self.b12,
+                    7 => &mut self.t012,
+                    _ => panic!("index out of bounds: the len is 8 but the index is {}", index),
+                }
+            }
+        }
+
+        impl_ref_ops!($Multivec3;
+            Add<$Multivec3>, add;
+            Sub<$Multivec3>, sub;
+            Mul<$Multivec3>, mul;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Multivec3;
+            AddAssign<$Multivec3>, add_assign, Add, add;
+            SubAssign<$Multivec3>, sub_assign, Sub, sub;
+            MulAssign<$Multivec3>, mul_assign, Mul, mul;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Multivec3, $t);
+        impl_ref_neg!($Multivec3);
+    };
+}
+
+impl_multivec3!(Multivec3, f32, Vec3, Bivec3, Rot, multivec3);
+impl_multivec3!(DMultivec3, f64, DVec3, DBivec3, DRot, dmultivec3);
+
+impl Multivec3 {
+    pub fn as_f64(self) -> DMultivec3 {
+        DMultivec3::from_array(self.to_array().map(|x| x as f64))
+    }
+}
+
+impl DMultivec3 {
+    pub fn as_f32(self) -> Multivec3 {
+        Multivec3::from_array(self.to_array().map(|x| x as f32))
+    }
+}
@@ -0,0 +1,856 @@
+use crate::{math, Vec3, Mat4, Bivec3, Rot, DVec3, DMat4, DBivec3, DRot};
+use crate::impl_ops::*;
+use core::{f32, ops::*};
+
+// 3D projective geometric algebra, R(3,0,1). On top of I, J, K there is a
+// fourth basis vector W that squares to zero, so translations become rotations
+// around lines at infinity and a single Motor covers any rigid motion.
+//
+// Planes are vectors, lines are bivectors and points are trivectors, but they
+// are stored by what they mean rather than by blade:
+// - Plane: i x + j y + k z + w = 0
+// - Line: the Euclidean bivector (dual of the direction, like Bivec3) and the
+//   ideal bivector W ^ I, W ^ J, W ^ K, which works out to the moment
+//   point x direction
+// - Point: (i, j, k) / w, w = 0 being a direction (a point at infinity)
+
+macro_rules! impl_pga {
+    ($Point:ident, $Line:ident, $Plane:ident, $Motor:ident, $t:ident,
+     $Vec3:ident, $Mat4:ident, $Bivec3:ident, $Rot:ident) => {
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Point {
+            pub i: $t,
+            pub j: $t,
+            pub k: $t,
+            pub w: $t,
+        }
+
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Line {
+            pub b01: $t,
+            pub b02: $t,
+            pub b12: $t,
+            pub bw0: $t,
+            pub bw1: $t,
+            pub bw2: $t,
+        }
+
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Plane {
+            pub i: $t,
+            pub j: $t,
+            pub k: $t,
+            pub w: $t,
+        }
+
+        // Even part of the algebra: a rotor (a, b01, b02, b12), the ideal
+        // bivector (bw0, bw1, bw2) and the pseudoscalar W ^ I ^ J ^ K. Applied
+        // as M x M† like Rot, and p * q applies q first.
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Motor {
+            pub a: $t,
+            pub b01: $t,
+            pub b02: $t,
+            pub b12: $t,
+            pub bw0: $t,
+            pub bw1: $t,
+            pub bw2: $t,
+            pub qw012: $t,
+        }
+
+        impl $Point {
+            pub const fn new(i: $t, j: $t, k: $t, w: $t) -> Self {
+                Self { i, j, k, w }
+            }
+
+            pub const fn from_array(array: [$t; 4]) -> Self {
+                Self::new(array[0], array[1], array[2], array[3])
+            }
+
+            pub const fn to_array(self) -> [$t; 4] {
+                [self.i, self.j, self.k, self.w]
+            }
+
+            pub fn as_array(&self) -> &[$t; 4] {
+                unsafe { &*(self as *const Self as *const [$t; 4]) }
+            }
+
+            pub fn abs_diff_eq(self, other: $Point, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Point, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+            }
+
+            pub const ORIGIN: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+            pub const fn from_vec3(vec: $Vec3) -> Self {
+                Self::new(vec.i, vec.j, vec.k, 1.0)
+            }
+
+            // Point at infinity, only affected by the rotation part of a motor
+            pub const fn from_direction(dir: $Vec3) -> Self {
+                Self::new(dir.i, dir.j, dir.k, 0.0)
+            }
+
+            // be careful, divide by zero error!
+            pub fn to_vec3(self) -> $Vec3 {
+                $Vec3::new(self.i, self.j, self.k) / self.w
+            }
+
+            // Scales w to 1
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.w
+            }
+
+            // None for points at infinity, or if w is infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let w_inv: $t = 1.0 / self.w;
+                if w_inv.is_finite() && w_inv != 0.0 {
+                    Some(self * w_inv)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl $Line {
+            pub const fn new(b01: $t, b02: $t, b12: $t, bw0: $t, bw1: $t, bw2: $t) -> Self {
+                Self { b01, b02, b12, bw0, bw1, bw2 }
+            }
+
+            pub const fn from_array(array: [$t; 6]) -> Self {
+                Self::new(array[0], array[1], array[2], array[3], array[4], array[5])
+            }
+
+            pub const fn to_array(self) -> [$t; 6] {
+                [self.b01, self.b02, self.b12, self.bw0, self.bw1, self.bw2]
+            }
+
+            pub fn as_array(&self) -> &[$t; 6] {
+                unsafe { &*(self as *const Self as *const [$t; 6]) }
+            }
+
+            pub fn abs_diff_eq(self, other: $Line, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Line, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+            }
+
+            pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+            pub fn from_point_dir(point: $Vec3, dir: $Vec3) -> Self {
+                let plane: $Bivec3 = $Bivec3::from_dual(dir);
+                let moment: $Vec3 = point.cross(dir);
+                Self::new(plane.b01, plane.b02, plane.b12, moment.i, moment.j, moment.k)
+            }
+
+            // Line through both points, pointing from p to q
+            pub fn from_points(p: $Vec3, q: $Vec3) -> Self {
+                Self::from_point_dir(p, q - p)
+            }
+
+            pub fn direction(self) -> $Vec3 {
+                self.euclidean().dual()
+            }
+
+            pub fn moment(self) -> $Vec3 {
+                $Vec3::new(self.bw0, self.bw1, self.bw2)
+            }
+
+            // The part through the origin, as a plane of rotation
+            pub fn euclidean(self) -> $Bivec3 {
+                $Bivec3::new(self.b01, self.b02, self.b12)
+            }
+
+            // Length of the direction, zero for lines at infinity
+            pub fn norm(self) -> $t {
+                self.euclidean().norm()
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+
+            // None for lines at infinity, or if the norm is infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            // Works the same way as Bivec3::exp. A unit line l times -angle / 2
+            // turns by angle around l, and a line at infinity gives a
+            // translation.
+            pub fn exp(self) -> $Motor {
+                let dir: $Vec3 = self.direction();
+                let moment: $Vec3 = self.moment();
+                let angle: $t = self.norm();
+                let (sinc, c2): ($t, $t) = if angle > 1e-4 {
+                    let sinc: $t = math::$t::sin(angle) / angle;
+                    (sinc, (math::$t::cos(angle) - sinc) / math::$t::powi(angle, 2))
+                } else {
+                    // Taylor series of sin(x)/x and (cos(x) - sin(x)/x)/x^2
+                    let angle_squared: $t = math::$t::powi(angle, 2);
+                    (1.0 - angle_squared / 6.0, -1.0 / 3.0 + angle_squared / 30.0)
+                };
+                let pitch: $t = dir * moment;
+                let ideal: $Vec3 = moment * sinc + dir * (pitch * c2);
+                $Motor::new(
+                    math::$t::cos(angle),
+                    self.b01 * sinc,
+                    self.b02 * sinc,
+                    self.b12 * sinc,
+                    ideal.i,
+                    ideal.j,
+                    ideal.k,
+                    pitch * sinc,
+                )
+            }
+        }
+
+        impl $Plane {
+            pub const fn new(i: $t, j: $t, k: $t, w: $t) -> Self {
+                Self { i, j, k, w }
+            }
+
+            pub const fn from_array(array: [$t; 4]) -> Self {
+                Self::new(array[0], array[1], array[2], array[3])
+            }
+
+            pub const fn to_array(self) -> [$t; 4] {
+                [self.i, self.j, self.k, self.w]
+            }
+
+            pub fn as_array(&self) -> &[$t; 4] {
+                unsafe { &*(self as *const Self as *const [$t; 4]) }
+            }
+
+            pub fn abs_diff_eq(self, other: $Plane, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+            }
+
+            pub fn relative_eq(self, other: $Plane, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+            }
+
+            pub fn from_normal_point(normal: $Vec3, point: $Vec3) -> Self {
+                Self::new(normal.i, normal.j, normal.k, -(normal * point))
+            }
+
+            // Normal follows the right hand rule going a, b, c
+            pub fn from_points(a: $Vec3, b: $Vec3, c: $Vec3) -> Self {
+                Self::from_normal_point((b - a).cross(c - a), a)
+            }
+
+            pub fn normal(self) -> $Vec3 {
+                $Vec3::new(self.i, self.j, self.k)
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.normal().norm()
+            }
+
+            // None for the plane at infinity, or if the normal is infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.normal().norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            // Signed, positive on the side the normal points to. The plane
+            // should be normalized.
+            pub fn dist(self, point: $Vec3) -> $t {
+                self.normal() * point + self.w
+            }
+        }
+
+        impl $Motor {
+            #[allow(clippy::too_many_arguments)]
+            pub const fn new(a: $t, b01: $t, b02: $t, b12: $t, bw0: $t, bw1: $t, bw2: $t, qw012: $t) -> Self {
+                Self { a, b01, b02, b12, bw0, bw1, bw2, qw012 }
+            }
+
+            pub const fn from_array(array: [$t; 8]) -> Self {
+                Self::new(array[0], array[1], array[2], array[3], array[4], array[5], array[6], array[7])
+            }
+
+            pub const fn to_array(self) -> [$t; 8] {
+                [self.a, self.b01, self.b02, self.b12, self.bw0, self.bw1, self.bw2, self.qw012]
+            }
+
+            pub fn as_array(&self) -> &[$t; 8] {
+                unsafe { &*(self as *const Self as *const [$t; 8]) }
+            }
+
+            // m and -m are the same motion, so both count as equal
+            pub fn abs_diff_eq(self, other: $Motor, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+                    || math::$t::abs_diff_eq(&self.to_array(), &(-other).to_array(), max_abs_diff)
+            }
+
+            // m and -m are the same motion, so both count as equal
+            pub fn relative_eq(self, other: $Motor, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+                    || math::$t::relative_eq(&self.to_array(), &(-other).to_array(), max_abs_diff, max_relative)
+            }
+
+            pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+            pub const fn from_rot(rot: $Rot) -> Self {
+                Self::new(rot.a, rot.b01, rot.b02, rot.b12, 0.0, 0.0, 0.0, 0.0)
+            }
+
+            pub fn from_translation(translation: $Vec3) -> Self {
+                Self::new(1.0, 0.0, 0.0, 0.0, -0.5 * translation.i, -0.5 * translation.j, -0.5 * translation.k, 0.0)
+            }
+
+            // Rotates first, then translates
+            pub fn from_rot_translation(rot: $Rot, translation: $Vec3) -> Self {
+                Self::from_translation(translation) * Self::from_rot(rot)
+            }
+
+            // Turns by angle around the line, counterclockwise when the line
+            // points towards the viewer
+            pub fn from_line_angle(line: $Line, angle: $t) -> Self {
+                (line.normalize() * (-0.5 * angle)).exp()
+            }
+
+            // The rotation part, applied before the translation
+            pub fn rot(self) -> $Rot {
+                $Rot::new(self.a, self.b01, self.b02, self.b12)
+            }
+
+            // The translation part, applied after the rotation. Read off the
+            // translator M R†.
+            pub fn translation(self) -> $Vec3 {
+                let scale: $t = -2.0 / self.norm_squared();
+                $Vec3::new(
+                    self.a * self.bw0 + self.b01 * self.bw1 + self.b02 * self.bw2 + self.b12 * self.qw012,
+                    self.a * self.bw1 - self.b01 * self.bw0 - self.b02 * self.qw012 + self.b12 * self.bw2,
+                    self.a * self.bw2 + self.b01 * self.qw012 - self.b02 * self.bw0 - self.b12 * self.bw1,
+                ) * scale
+            }
+
+            // Only the rotation part counts, the rest has no Euclidean length
+            pub fn norm(self) -> $t {
+                self.rot().norm()
+            }
+
+            pub fn norm_squared(self) -> $t {
+                self.rot().norm_squared()
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+
+            // None if the rotation part is zero, infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            pub fn reverse(self) -> Self {
+                Self {
+                    a: self.a,
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b12: -self.b12,
+                    bw0: -self.bw0,
+                    bw1: -self.bw1,
+                    bw2: -self.bw2,
+                    qw012: self.qw012,
+                }
+            }
+
+            // For unit motors this is the same as the reverse
+            pub fn inverse(self) -> Self {
+                self.reverse() / self.norm_squared()
+            }
+
+            // The transform_ functions expect a unit motor and work out M x M†
+            // as a rotation followed by a translation
+            pub fn transform_point(self, point: $Point) -> $Point {
+                let rot: $Rot = self.rot();
+                let p: $Vec3 = rot.rotate($Vec3::new(point.i, point.j, point.k)) + self.translation() * point.w;
+                $Point::new(p.i, p.j, p.k, point.w)
+            }
+
+            pub fn transform_line(self, line: $Line) -> $Line {
+                let rot: $Rot = self.rot();
+                let plane: $Bivec3 = $Bivec3::from_dual(rot.rotate(line.direction()));
+                let moment: $Vec3 = rot.rotate(line.moment()) - plane.inner(self.translation());
+                $Line::new(plane.b01, plane.b02, plane.b12, moment.i, moment.j, moment.k)
+            }
+
+            pub fn transform_plane(self, plane: $Plane) -> $Plane {
+                let normal: $Vec3 = self.rot().rotate(plane.normal());
+                $Plane::new(normal.i, normal.j, normal.k, plane.w - normal * self.translation())
+            }
+
+            // Inverse of Line::exp for unit motors. m and -m are the same motion,
+            // the one with a >= 0 is used so the angle stays below pi.
+            pub fn log(self) -> $Line {
+                let m: $Motor = if self.a < 0.0 { -self } else { self };
+                let plane: $Bivec3 = m.rot().log();
+                let dir: $Vec3 = plane.dual();
+                let angle: $t = plane.norm();
+                let (sinc, c2): ($t, $t) = if angle > 1e-4 {
+                    let sinc: $t = math::$t::sin(angle) / angle;
+                    (sinc, (math::$t::cos(angle) - sinc) / math::$t::powi(angle, 2))
+                } else {
+                    let angle_squared: $t = math::$t::powi(angle, 2);
+                    (1.0 - angle_squared / 6.0, -1.0 / 3.0 + angle_squared / 30.0)
+                };
+                let pitch: $t = m.qw012 / sinc;
+                let moment: $Vec3 = ($Vec3::new(m.bw0, m.bw1, m.bw2) - dir * (pitch * c2)) / sinc;
+                $Line::new(plane.b01, plane.b02, plane.b12, moment.i, moment.j, moment.k)
+            }
+
+            // Screw linear interpolation, moves along a single screw motion
+            // with constant speed. Takes the shortest arc like Rot::slerp.
+            pub fn sclerp(self, other: $Motor, t: $t) -> Self {
+                let other: $Motor = if self.rot().dot(other.rot()) < 0.0 { -other } else { other };
+                self * ((self.reverse() * other).log() * t).exp()
+            }
+
+            // Expects a unit motor
+            pub fn to_mat4(self) -> $Mat4 {
+                $Mat4::from_translation(self.translation()) * $Mat4::from_rot(self.rot())
+            }
+        }
+
+        impl From<$Vec3> for $Point {
+            fn from(vec: $Vec3) -> Self {
+                Self::from_vec3(vec)
+            }
+        }
+
+        impl From<$Rot> for $Motor {
+            fn from(rot: $Rot) -> Self {
+                Self::from_rot(rot)
+            }
+        }
+
+        impl Add<$Point> for $Point {
+            type Output = Self;
+            fn add(self, val: Self) -> Self {
+                Self {
+                    i: self.i.add(val.i),
+                    j: self.j.add(val.j),
+                    k: self.k.add(val.k),
+                    w: self.w.add(val.w),
+                }
+            }
+        }
+
+        impl Sub<$Point> for $Point {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self {
+                Self {
+                    i: self.i.sub(val.i),
+                    j: self.j.sub(val.j),
+                    k: self.k.sub(val.k),
+                    w: self.w.sub(val.w),
+                }
+            }
+        }
+
+        impl Mul<$t> for $Point {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    i: self.i.mul(val),
+                    j: self.j.mul(val),
+                    k: self.k.mul(val),
+                    w: self.w.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Point {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    i: self.i.div(val),
+                    j: self.j.div(val),
+                    k: self.k.div(val),
+                    w: self.w.div(val),
+                }
+            }
+        }
+
+        impl Neg for $Point {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    i: -self.i,
+                    j: -self.j,
+                    k: -self.k,
+                    w: -self.w,
+                }
+            }
+        }
+
+        impl Add<$Line> for $Line {
+            type Output = Self;
+            fn add(self, val: Self) -> Self {
+                Self {
+                    b01: self.b01.add(val.b01),
+                    b02: self.b02.add(val.b02),
+                    b12: self.b12.add(val.b12),
+                    bw0: self.bw0.add(val.bw0),
+                    bw1: self.bw1.add(val.bw1),
+                    bw2: self.bw2.add(val.bw2),
+                }
+            }
+        }
+
+        impl Sub<$Line> for $Line {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self {
+                Self {
+                    b01: self.b01.sub(val.b01),
+                    b02: self.b02.sub(val.b02),
+                    b12: self.b12.sub(val.b12),
+                    bw0: self.bw0.sub(val.bw0),
+                    bw1: self.bw1.sub(val.bw1),
+                    bw2: self.bw2.sub(val.bw2),
+                }
+            }
+        }
+
+        impl Mul<$t> for $Line {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    b01: self.b01.mul(val),
+                    b02: self.b02.mul(val),
+                    b12: self.b12.mul(val),
+                    bw0: self.bw0.mul(val),
+                    bw1: self.bw1.mul(val),
+                    bw2: self.bw2.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Line {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    b01: self.b01.div(val),
+                    b02: self.b02.div(val),
+                    b12: self.b12.div(val),
+                    bw0: self.bw0.div(val),
+                    bw1: self.bw1.div(val),
+                    bw2: self.bw2.div(val),
+                }
+            }
+        }
+
+        impl Neg for $Line {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b12: -self.b12,
+                    bw0: -self.bw0,
+                    bw1: -self.bw1,
+                    bw2: -self.bw2,
+                }
+            }
+        }
+
+        impl Add<$Plane> for $Plane {
+            type Output = Self;
+            fn add(self, val: Self) -> Self {
+                Self {
+                    i: self.i.add(val.i),
+                    j: self.j.add(val.j),
+                    k: self.k.add(val.k),
+                    w: self.w.add(val.w),
+                }
+            }
+        }
+
+        impl Sub<$Plane> for $Plane {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self {
+                Self {
+                    i: self.i.sub(val.i),
+                    j: self.j.sub(val.j),
+                    k: self.k.sub(val.k),
+                    w: self.w.sub(val.w),
+                }
+            }
+        }
+
+        impl Mul<$t> for $Plane {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    i: self.i.mul(val),
+                    j: self.j.mul(val),
+                    k: self.k.mul(val),
+                    w: self.w.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Plane {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    i: self.i.div(val),
+                    j: self.j.div(val),
+                    k: self.k.div(val),
+                    w: self.w.div(val),
+                }
+            }
+        }
+
+        impl Neg for $Plane {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    i: -self.i,
+                    j: -self.j,
+                    k: -self.k,
+                    w: -self.w,
+                }
+            }
+        }
+
+        impl Mul<$t> for $Motor {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    a: self.a.mul(val),
+                    b01: self.b01.mul(val),
+                    b02: self.b02.mul(val),
+                    b12: self.b12.mul(val),
+                    bw0: self.bw0.mul(val),
+                    bw1: self.bw1.mul(val),
+                    bw2: self.bw2.mul(val),
+                    qw012: self.qw012.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Motor {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    a: self.a.div(val),
+                    b01: self.b01.div(val),
+                    b02: self.b02.div(val),
+                    b12: self.b12.div(val),
+                    bw0: self.bw0.div(val),
+                    bw1: self.bw1.div(val),
+                    bw2: self.bw2.div(val),
+                    qw012: self.qw012.div(val),
+                }
+            }
+        }
+
+        impl Neg for $Motor {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    a: -self.a,
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b12: -self.b12,
+                    bw0: -self.bw0,
+                    bw1: -self.bw1,
+                    bw2: -self.bw2,
+                    qw012: -self.qw012,
+                }
+            }
+        }
+
+        // Geometric product, the rotor part is the same as Rot * Rot
+        impl Mul<$Motor> for $Motor {
+            type Output = Self;
+            fn mul(self, val: Self) -> Self {
+                let p: $Motor = self;
+                let q: $Motor = val;
+                Self {
+                    a: p.a * q.a - p.b01 * q.b01 - p.b02 * q.b02 - p.b12 * q.b12,
+                    b01: p.a * q.b01 + p.b01 * q.a - p.b02 * q.b12 + p.b12 * q.b02,
+                    b02: p.a * q.b02 + p.b01 * q.b12 + p.b02 * q.a - p.b12 * q.b01,
+                    b12: p.a * q.b12 - p.b01 * q.b02 + p.b02 * q.b01 + p.b12 * q.a,
+                    bw0: p.a * q.bw0 + p.b01 * q.bw1 + p.b02 * q.bw2 - p.b12 * q.qw012
+                       + p.bw0 * q.a - p.bw1 * q.b01 - p.bw2 * q.b02 - p.qw012 * q.b12,
+                    bw1: p.a * q.bw1 - p.b01 * q.bw0 + p.b02 * q.qw012 + p.b12 * q.bw2
+                       + p.bw0 * q.b01 + p.bw1 * q.a - p.bw2 * q.b12 + p.qw012 * q.b02,
+                    bw2: p.a * q.bw2 - p.b01 * q.qw012 - p.b02 * q.bw0 - p.b12 * q.bw1
+                       + p.bw0 * q.b02 + p.bw1 * q.b12 + p.bw2 * q.a - p.qw012 * q.b01,
+                    qw012: p.a * q.qw012 + p.b01 * q.bw2 - p.b02 * q.bw1 + p.b12 * q.bw0
+                         + p.bw0 * q.b12 - p.bw1 * q.b02 + p.bw2 * q.b01 + p.qw012 * q.a,
+                }
+            }
+        }
+
+        impl Mul<$Point> for $Motor {
+            type Output = $Point;
+            fn mul(self, point: $Point) -> $Point {
+                self.transform_point(point)
+            }
+        }
+
+        impl Mul<$Line> for $Motor {
+            type Output = $Line;
+            fn mul(self, line: $Line) -> $Line {
+                self.transform_line(line)
+            }
+        }
+
+        impl Mul<$Plane> for $Motor {
+            type Output = $Plane;
+            fn mul(self, plane: $Plane) -> $Plane {
+                self.transform_plane(plane)
+            }
+        }
+
+        impl_ref_ops!($Point;
+            Add<$Point>, add;
+            Sub<$Point>, sub;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Point;
+            AddAssign<$Point>, add_assign, Add, add;
+            SubAssign<$Point>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_ref_ops!($Line;
+            Add<$Line>, add;
+            Sub<$Line>, sub;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Line;
+            AddAssign<$Line>, add_assign, Add, add;
+            SubAssign<$Line>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_ref_ops!($Plane;
+            Add<$Plane>, add;
+            Sub<$Plane>, sub;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Plane;
+            AddAssign<$Plane>, add_assign, Add, add;
+            SubAssign<$Plane>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_ref_ops!($Motor;
+            Mul<$Motor>, mul;
+            Mul<$Point>, mul;
+            Mul<$Line>, mul;
+            Mul<$Plane>, mul;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Motor;
+            MulAssign<$Motor>, mul_assign, Mul, mul;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Point, $t);
+        impl_scalar_lhs_mul!($Line, $t);
+        impl_scalar_lhs_mul!($Plane, $t);
+        impl_scalar_lhs_mul!($Motor, $t);
+        impl_ref_neg!($Point);
+        impl_ref_neg!($Line);
+        impl_ref_neg!($Plane);
+        impl_ref_neg!($Motor);
+    };
+}
+
+impl_pga!(Point, Line, Plane, Motor, f32, Vec3, Mat4, Bivec3, Rot);
+impl_pga!(DPoint, DLine, DPlane, DMotor, f64, DVec3, DMat4, DBivec3, DRot);
+
+impl Point {
+    pub fn as_f64(self) -> DPoint {
+        DPoint::from_array(self.to_array().map(|x| x as f64))
+    }
+}
+
+impl DPoint {
+    pub fn as_f32(self) -> Point {
+        Point::from_array(self.to_array().map(|x| x as f32))
+    }
+}
+
+impl Line {
+    pub fn as_f64(self) -> DLine {
+        DLine::from_array(self.to_array().map(|x| x as f64))
+    }
+}
+
+impl DLine {
+    pub fn as_f32(self) -> Line {
+        Line::from_array(self.to_array().map(|x| x as f32))
+    }
+}
+
+impl Plane {
+    pub fn as_f64(self) -> DPlane {
+        DPlane::from_array(self.to_array().map(|x| x as f64))
+    }
+}
+
+impl DPlane {
+    pub fn as_f32(self) -> Plane {
+        Plane::from_array(self.to_array().map(|x| x as f32))
+    }
+}
+
+impl Motor {
+    pub fn as_f64(self) -> DMotor {
+        DMotor::from_array(self.to_array().map(|x| x as f64))
+    }
+}
+
+impl DMotor {
+    pub fn as_f32(self) -> Motor {
+        Motor::from_array(self.to_array().map(|x| x as f32))
+    }
+}
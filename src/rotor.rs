@@ -1,63 +1,1189 @@
-use crate::{Vec3, Vec4, Mat4, Bivec3};
+use crate::{math, EulerOrder, Vec2, Vec3, Vec4, Mat2, Mat3, Mat4, Bivec2, Bivec3, Bivec4, DVec2, DVec3, DVec4, DMat2, DMat3, DMat4, DBivec2, DBivec3, DBivec4};
+use crate::impl_ops::*;
 use core::{f32, ops::*};
 
-const fn rot(a: f32, b01: f32, b02: f32, b12: f32) -> Rot {
-    Rot::new(a, b01, b02, b12)
+macro_rules! impl_rot2 {
+    ($Rot2:ident, $t:ident, $Vec2:ident, $Mat2:ident, $Mat3:ident, $Bivec2:ident, $rot2:ident) => {
+        #[allow(dead_code)]
+        const fn $rot2(a: $t, b01: $t) -> $Rot2 {
+            $Rot2::new(a, b01)
+        }
+
+        // 2D rotor, R = a + b01 I ^ J applied as R v R† like Rot. Unlike a raw
+        // angle it never wraps, and p * q applies q first.
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Rot2 {
+            pub a: $t,
+            pub b01: $t,
+        }
+
+        impl $Rot2 {
+            pub const fn new(a: $t, b01: $t) -> Self {
+                Self { a, b01 }
+            }
+
+            pub const fn from_array(array: [$t; 2]) -> Self {
+                Self::new(array[0], array[1])
+            }
+
+            pub const fn to_array(self) -> [$t; 2] {
+                [self.a, self.b01]
+            }
+
+            pub fn as_array(&self) -> &[$t; 2] {
+                unsafe { &*(self as *const Self as *const [$t; 2]) }
+            }
+
+            // r and -r are the same rotation, so both count as equal
+            pub fn abs_diff_eq(self, other: $Rot2, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+                    || math::$t::abs_diff_eq(&self.to_array(), &(-other).to_array(), max_abs_diff)
+            }
+
+            // r and -r are the same rotation, so both count as equal
+            pub fn relative_eq(self, other: $Rot2, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+                    || math::$t::relative_eq(&self.to_array(), &(-other).to_array(), max_abs_diff, max_relative)
+            }
+
+            pub const IDENTITY: Self = Self::new(1.0, 0.0);
+
+            // Counterclockwise, turning I towards J like Mat3::from_rotation
+            pub fn from_angle(angle: $t) -> Self {
+                Self::new(math::$t::cos(angle / 2.0), -math::$t::sin(angle / 2.0))
+            }
+
+            // Signed angle between -pi and pi, inverse of Rot2::from_angle. r and
+            // -r are the same rotation, the one with a >= 0 is used.
+            pub fn angle(self) -> $t {
+                let r: $Rot2 = if self.a < 0.0 { -self } else { self };
+                2.0 * math::$t::atan2(-r.b01, r.a)
+            }
+
+            // Returns rotation from a vector to another, neither has to be
+            // normalized. Opposite vectors get a half turn and a zero vector
+            // gets the identity.
+            pub fn from_vecs(from: $Vec2, to: $Vec2) -> Self {
+                let (from, to): ($Vec2, $Vec2) = match (from.try_normalize(), to.try_normalize()) {
+                    (Some(from), Some(to)) => (from, to),
+                    _ => return Self::IDENTITY,
+                };
+
+                // Same as Rot::from_vecs, (1 + to * from) with 1 + to . from
+                // as half of |from + to|^2
+                let a: $t = (from + to).norm_squared() * 0.5;
+                Self::new(a, $Bivec2::wedge(to, from).b01).try_normalize().unwrap_or(Self::new(0.0, 1.0))
+            }
+
+            pub fn dot(self, other: $Rot2) -> $t {
+                self.a * other.a + self.b01 * other.b01
+            }
+
+            pub fn norm(self) -> $t {
+                math::$t::sqrt(self.norm_squared())
+            }
+
+            pub fn norm_squared(self) -> $t {
+                math::$t::powi(self.a, 2)
+              + math::$t::powi(self.b01, 2)
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+
+            // None if the norm is zero, infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            pub fn normalize_or_identity(self) -> Self {
+                self.try_normalize().unwrap_or(Self::IDENTITY)
+            }
+
+            // Zero is not a rotation, see Rot::normalize_or_zero
+            pub fn normalize_or_zero(self) -> Self {
+                self.try_normalize().unwrap_or(Self::new(0.0, 0.0))
+            }
+
+            // Checks the squared norm against 1
+            pub fn is_normalized(self, epsilon: $t) -> bool {
+                (self.norm_squared() - 1.0).abs() <= epsilon
+            }
+
+            // Inverse of Bivec2::exp for unit rotors
+            pub fn log(self) -> $Bivec2 {
+                $Bivec2::new(math::$t::atan2(self.b01, self.a))
+            }
+
+            pub fn reverse(self) -> Self {
+                Self {
+                    a: self.a,
+                    b01: -self.b01,
+                }
+            }
+
+            // For unit rotors this is the same as the reverse
+            pub fn inverse(self) -> Self {
+                self.reverse() / self.norm_squared()
+            }
+
+            pub fn rotate(self, vec: $Vec2) -> $Vec2 {
+                // R v R† = v R†^2 since I ^ J anticommutes with vectors in the plane
+                let cos: $t = self.a * self.a - self.b01 * self.b01;
+                let sin: $t = -2.0 * self.a * self.b01;
+                $Vec2::new(cos * vec.i - sin * vec.j, sin * vec.i + cos * vec.j)
+            }
+
+            pub fn to_mat2(self) -> $Mat2 {
+                $Mat2::from_rot2(self)
+            }
+
+            pub fn to_mat3(self) -> $Mat3 {
+                $Mat3::from_rot2(self)
+            }
+
+            // Normalized linear interpolation, takes the shortest arc
+            pub fn nlerp(self, other: $Rot2, t: $t) -> Self {
+                let other: $Rot2 = if self.dot(other) < 0.0 { -other } else { other };
+                (self * (1.0 - t) + other * t).normalize()
+            }
+
+            // Spherical linear interpolation, takes the shortest arc. In 2D this
+            // is turning by a fraction of the angle in between.
+            pub fn slerp(self, other: $Rot2, t: $t) -> Self {
+                let other: $Rot2 = if self.dot(other) < 0.0 { -other } else { other };
+                self * ((self.reverse() * other).log() * t).exp()
+            }
+        }
+
+        impl Add<$Rot2> for $Rot2 {
+            type Output = Self;
+            fn add(self, val: Self) -> Self {
+                Self {
+                    a: self.a.add(val.a),
+                    b01: self.b01.add(val.b01),
+                }
+            }
+        }
+
+        impl Sub<$Rot2> for $Rot2 {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self {
+                Self {
+                    a: self.a.sub(val.a),
+                    b01: self.b01.sub(val.b01),
+                }
+            }
+        }
+
+        impl Neg for $Rot2 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    a: -self.a,
+                    b01: -self.b01,
+                }
+            }
+        }
+
+        impl Mul<$t> for $Rot2 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    a: self.a.mul(val),
+                    b01: self.b01.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Rot2 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    a: self.a.div(val),
+                    b01: self.b01.div(val),
+                }
+            }
+        }
+
+        // Geometric product, 2D rotors commute so the order only matters for
+        // matching Rot
+        impl Mul<$Rot2> for $Rot2 {
+            type Output = Self;
+            fn mul(self, val: Self) -> Self {
+                Self {
+                    a: self.a * val.a - self.b01 * val.b01,
+                    b01: self.a * val.b01 + self.b01 * val.a,
+                }
+            }
+        }
+
+        impl Mul<$Vec2> for $Rot2 {
+            type Output = $Vec2;
+            fn mul(self, vec: $Vec2) -> $Vec2 {
+                self.rotate(vec)
+            }
+        }
+
+        impl Index<usize> for $Rot2 {
+            type Output = $t;
+            fn index(&self, index: usize) -> &$t {
+                match index {
+                    0 => &self.a,
+                    1 => &self.b01,
+                    _ => panic!("index out of bounds: the len is 2 but the index is {}", index),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $Rot2 {
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                match index {
+                    0 => &mut self.a,
+                    1 => &mut self.b01,
+                    _ => panic!("index out of bounds: the len is 2 but the index is {}", index),
+                }
+            }
+        }
+
+        impl_ref_ops!($Rot2;
+            Add<$Rot2>, add;
+            Sub<$Rot2>, sub;
+            Mul<$Rot2>, mul;
+            Mul<$Vec2>, mul;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Rot2;
+            AddAssign<$Rot2>, add_assign, Add, add;
+            SubAssign<$Rot2>, sub_assign, Sub, sub;
+            MulAssign<$Rot2>, mul_assign, Mul, mul;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Rot2, $t);
+        impl_ref_neg!($Rot2);
+    };
+}
+
+macro_rules! impl_rot {
+    ($Rot:ident, $t:ident, $Vec3:ident, $Mat3:ident, $Mat4:ident, $Bivec3:ident, $rot:ident) => {
+        #[allow(dead_code)]
+        const fn $rot(a: $t, b01: $t, b02: $t, b12: $t) -> $Rot {
+            $Rot::new(a, b01, b02, b12)
+        }
+
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Rot {
+            pub a: $t,
+            pub b01: $t,
+            pub b02: $t,
+            pub b12: $t,
+        }
+
+        impl $Rot {
+            pub const fn new(a: $t, b01: $t, b02: $t, b12: $t) -> Self {
+                Self { a, b01, b02, b12 }
+            }
+
+            pub const fn from_array(array: [$t; 4]) -> Self {
+                Self::new(array[0], array[1], array[2], array[3])
+            }
+
+            pub const fn to_array(self) -> [$t; 4] {
+                [self.a, self.b01, self.b02, self.b12]
+            }
+
+            pub fn as_array(&self) -> &[$t; 4] {
+                unsafe { &*(self as *const Self as *const [$t; 4]) }
+            }
+
+            // r and -r are the same rotation, so both count as equal
+            pub fn abs_diff_eq(self, other: $Rot, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+                    || math::$t::abs_diff_eq(&self.to_array(), &(-other).to_array(), max_abs_diff)
+            }
+
+            // r and -r are the same rotation, so both count as equal
+            pub fn relative_eq(self, other: $Rot, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+                    || math::$t::relative_eq(&self.to_array(), &(-other).to_array(), max_abs_diff, max_relative)
+            }
+
+            pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+
+            pub fn dot(self, other: $Rot) -> $t {
+                self.a * other.a + self.b01 * other.b01 + self.b02 * other.b02 + self.b12 * other.b12
+            }
+
+            pub fn norm(self) -> $t {
+                math::$t::sqrt(math::$t::powi(self.a, 2)
+                        + math::$t::powi(self.b01, 2) 
+                        + math::$t::powi(self.b02, 2) 
+                        + math::$t::powi(self.b12, 2))
+            }
+
+            pub fn norm_squared(self) -> $t {
+                math::$t::powi(self.a, 2)
+              + math::$t::powi(self.b01, 2) 
+              + math::$t::powi(self.b02, 2) 
+              + math::$t::powi(self.b12, 2) 
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+
+            // None if the norm is zero, infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            pub fn normalize_or(self, fallback: $Rot) -> Self {
+                self.try_normalize().unwrap_or(fallback)
+            }
+
+            // A zero rotor is no rotation at all, so identity is the neutral fallback
+            pub fn normalize_or_identity(self) -> Self {
+                self.normalize_or(Self::IDENTITY)
+            }
+
+            // Same as the vectors, but zero is not a rotation, so this is only
+            // useful to spot the failure. Rot::normalize_or_identity is usually
+            // what you want.
+            pub fn normalize_or_zero(self) -> Self {
+                self.normalize_or(Self::new(0.0, 0.0, 0.0, 0.0))
+            }
+
+            // Checks the squared norm against 1
+            pub fn is_normalized(self, epsilon: $t) -> bool {
+                (self.norm_squared() - 1.0).abs() <= epsilon
+            }
+
+            // Returns rotation from a vector to another, neither has to be
+            // normalized. Opposite vectors get a half turn in some plane
+            // containing them, and a zero vector gets the identity.
+            pub fn from_vecs(from: $Vec3, to: $Vec3) -> Self {
+                let (from, to): ($Vec3, $Vec3) = match (from.try_normalize(), to.try_normalize()) {
+                    (Some(from), Some(to)) => (from, to),
+                    _ => return Self::IDENTITY,
+                };
+
+                // The rotor is (1 + to * from), so the wedge is taken as to ^ from.
+                // 1 + to . from is half of |from + to|^2, written that way it keeps
+                // its digits when the vectors are almost opposite
+                let a: $t = (from + to).norm_squared() * 0.5;
+                let wedge_prod: $Bivec3 = $Bivec3::wedge(to, from);
+                let rot: Self = Self {
+                    a,
+                    b01: wedge_prod.b01,
+                    b02: wedge_prod.b02,
+                    b12: wedge_prod.b12,
+                };
+
+                // Exactly opposite, any plane containing from works
+                rot.try_normalize().unwrap_or_else(|| {
+                    let plane: $Bivec3 = $Bivec3::wedge(from.any_orthogonal(), from).normalize();
+                    Self::new(0.0, plane.b01, plane.b02, plane.b12)
+                })
+            }
+
+            // Rotation from a vector to another that also turns the part of up
+            // perpendicular to from into the part of up perpendicular to to,
+            // so there is no roll around to. Falls back to Rot::from_vecs when
+            // up is parallel to either vector.
+            pub fn from_vecs_with_up(from: $Vec3, to: $Vec3, up: $Vec3) -> Self {
+                if up.cross(from).try_normalize().is_none() || up.cross(to).try_normalize().is_none() {
+                    return Self::from_vecs(from, to);
+                }
+                Self::look_rotation_left(to, up) * Self::look_rotation_left(from, up).reverse()
+            }
+
+            // Rotation by angle in the plane, going from the first vector of the
+            // plane towards the second
+            pub fn from_angle_plane(angle: $t, plane: $Bivec3) -> Self {
+                let plane_norm: $t = plane.norm();
+                let sin: $t = math::$t::sin(angle * 0.5);
+                Self {
+                    a: math::$t::cos(angle * 0.5),
+                    b01: -sin * plane.b01 / plane_norm,
+                    b02: -sin * plane.b02 / plane_norm,
+                    b12: -sin * plane.b12 / plane_norm,
+                }
+            }
+
+            // Counterclockwise turn about the I, J or K axis, for the Euler angles
+            fn from_axis_angle_index(axis: usize, angle: $t) -> Self {
+                match axis {
+                    0 => Self::from_angle_plane(angle, $Bivec3::B12),
+                    1 => Self::from_angle_plane(angle, -$Bivec3::B02),
+                    _ => Self::from_angle_plane(angle, $Bivec3::B01),
+                }
+            }
+
+            // Angles in radians, a is applied first, see EulerOrder
+            pub fn from_euler(order: EulerOrder, a: $t, b: $t, c: $t) -> Self {
+                let [first, second, third] = order.axes();
+                Self::from_axis_angle_index(third, c)
+                    * Self::from_axis_angle_index(second, b)
+                    * Self::from_axis_angle_index(first, a)
+            }
+
+            // Inverse of Rot::from_euler for unit rotors, b is between -pi/2 and
+            // pi/2. In gimbal lock (b = ±pi/2) only a combination of a and c
+            // matters, so c is set to zero.
+            pub fn to_euler(self, order: EulerOrder) -> ($t, $t, $t) {
+                let [first, second, third] = order.axes();
+                let sign: $t = order.parity() as $t;
+                let mat: $Mat3 = self.to_mat3();
+                // Row r, column c
+                let m = |r: usize, c: usize| -> $t { mat[c][r] };
+
+                let cos_b: $t = math::$t::sqrt(math::$t::powi(m(first, first), 2) + math::$t::powi(m(second, first), 2));
+                let b: $t = math::$t::atan2(-sign * m(third, first), cos_b);
+                if cos_b > math::$t::sqrt($t::EPSILON) {
+                    let a: $t = math::$t::atan2(sign * m(third, second), m(third, third));
+                    let c: $t = math::$t::atan2(sign * m(second, first), m(first, first));
+                    (a, b, c)
+                } else {
+                    let a: $t = math::$t::atan2(-sign * m(second, third), m(second, second));
+                    (a, b, 0.0)
+                }
+            }
+
+            // Inverse of Bivec3::exp for unit rotors, the angle of the result
+            // is up to pi so r and -r give different logs
+            pub fn log(self) -> $Bivec3 {
+                let b_norm: $t = math::$t::sqrt(math::$t::powi(self.b01, 2)
+                                          + math::$t::powi(self.b02, 2)
+                                          + math::$t::powi(self.b12, 2));
+                if b_norm == 0.0 && self.a < 0.0 {
+                    // -1 is a full turn in any plane
+                    return $Bivec3::new(core::$t::consts::PI, 0.0, 0.0);
+                }
+                let scale: $t = if b_norm > 1e-4 || self.a < 0.0 {
+                    math::$t::atan2(b_norm, self.a) / b_norm
+                } else {
+                    // atan2(x, a) / x ~ 1 / a as x goes to zero
+                    1.0 / self.a
+                };
+                $Bivec3::new(self.b01 * scale, self.b02 * scale, self.b12 * scale)
+            }
+
+            // Orientation that turns +K towards dir, keeping +J as close to up as
+            // possible. This is the camera rotation undone by Mat4::look_to_left.
+            pub fn look_rotation_left(dir: $Vec3, up: $Vec3) -> Self {
+                let f: $Vec3 = dir.normalize();
+                let s: $Vec3 = up.cross(f).normalize();
+                let u: $Vec3 = f.cross(s);
+                Self::from_mat3($Mat3::from_cols(s, u, f))
+            }
+
+            // Orientation that turns -K towards dir, keeping +J as close to up as
+            // possible. This is the camera rotation undone by Mat4::look_to_right.
+            pub fn look_rotation_right(dir: $Vec3, up: $Vec3) -> Self {
+                Self::look_rotation_left(-dir, up)
+            }
+
+            // Expects an orthonormal matrix without reflection. Picks the largest of
+            // the diagonal terms to divide by so it stays stable near 180 degrees.
+            pub fn from_mat3(mat: $Mat3) -> Self {
+                let (m00, m10, m20) = (mat.col1.i, mat.col1.j, mat.col1.k);
+                let (m01, m11, m21) = (mat.col2.i, mat.col2.j, mat.col2.k);
+                let (m02, m12, m22) = (mat.col3.i, mat.col3.j, mat.col3.k);
+
+                // The bivector parts are the quaternion's x, y, z mapped onto their
+                // planes: b12 = -x, b02 = y, b01 = -z
+                let trace: $t = m00 + m11 + m22;
+                if trace > 0.0 {
+                    let s: $t = math::$t::sqrt(trace + 1.0) * 2.0;
+                    Self {
+                        a: 0.25 * s,
+                        b01: (m01 - m10) / s,
+                        b02: (m02 - m20) / s,
+                        b12: (m12 - m21) / s,
+                    }.normalize()
+                } else if m00 > m11 && m00 > m22 {
+                    let s: $t = math::$t::sqrt(1.0 + m00 - m11 - m22) * 2.0;
+                    Self {
+                        a: (m21 - m12) / s,
+                        b01: -(m02 + m20) / s,
+                        b02: (m01 + m10) / s,
+                        b12: -0.25 * s,
+                    }.normalize()
+                } else if m11 > m22 {
+                    let s: $t = math::$t::sqrt(1.0 + m11 - m00 - m22) * 2.0;
+                    Self {
+                        a: (m02 - m20) / s,
+                        b01: -(m12 + m21) / s,
+                        b02: 0.25 * s,
+                        b12: -(m01 + m10) / s,
+                    }.normalize()
+                } else {
+                    let s: $t = math::$t::sqrt(1.0 + m22 - m00 - m11) * 2.0;
+                    Self {
+                        a: (m10 - m01) / s,
+                        b01: -0.25 * s,
+                        b02: (m12 + m21) / s,
+                        b12: -(m02 + m20) / s,
+                    }.normalize()
+                }
+            }
+
+            pub fn to_mat3(self) -> $Mat3 {
+                $Mat3::from_rot(self)
+            }
+
+            pub fn to_mat4(self) -> $Mat4 {
+                $Mat4::from_rot(self)
+            }
+
+            pub fn reverse(self) -> Self {
+                Self {
+                    a: self.a,
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b12: -self.b12,
+                }
+            }
+
+            // For unit rotors this is the same as the reverse
+            pub fn inverse(self) -> Self {
+                let norm_squared_inv: $t = 1.0 / self.norm_squared();
+                Self {
+                    a: self.a * norm_squared_inv,
+                    b01: -self.b01 * norm_squared_inv,
+                    b02: -self.b02 * norm_squared_inv,
+                    b12: -self.b12 * norm_squared_inv,
+                }
+            }
+
+            // Sandwich product R v R†, the rotor is assumed to be normalized
+            pub fn rotate(self, vec: $Vec3) -> $Vec3 {
+                let p: $Rot = self;
+
+                // q = R v
+                let q: $Vec3 = $Vec3::new(
+                    p.a * vec.i + vec.j * p.b01 + vec.k * p.b02,
+                    p.a * vec.j - vec.i * p.b01 + vec.k * p.b12,
+                    p.a * vec.k - vec.i * p.b02 - vec.j * p.b12,
+                );
+                let q012: $t = vec.i * p.b12 - vec.j * p.b02 + vec.k * p.b01;
+
+                // q R†
+                $Vec3::new(
+                    p.a * q.i + q.j * p.b01 + q.k * p.b02 + q012 * p.b12,
+                    p.a * q.j - q.i * p.b01 - q012 * p.b02 + q.k * p.b12,
+                    p.a * q.k + q012 * p.b01 - q.i * p.b02 - q.j * p.b12,
+                )
+            }
+
+            // Normalized linear interpolation, takes the shortest arc
+            pub fn nlerp(self, other: $Rot, t: $t) -> Self {
+                let other: $Rot = if self.dot(other) < 0.0 { -other } else { other };
+                (self * (1.0 - t) + other * t).normalize()
+            }
+
+            // Spherical linear interpolation, takes the shortest arc
+            pub fn slerp(self, other: $Rot, t: $t) -> Self {
+                if self.dot(other) < 0.0 {
+                    self.slerp_unflipped(-other, t)
+                } else {
+                    self.slerp_unflipped(other, t)
+                }
+            }
+
+            // squad needs the inner slerps to follow the given signs exactly
+            fn slerp_unflipped(self, other: $Rot, t: $t) -> Self {
+                let cos: $t = self.dot(other);
+
+                // Nearly identical rotors, sin(theta) is too small to divide by
+                if cos > 0.9995 {
+                    return (self * (1.0 - t) + other * t).normalize();
+                }
+
+                // Nearly opposite rotors, a full turn apart. The lerp would pass
+                // through zero, so go by way of a rotor a quarter of the way
+                // round from both of them.
+                if cos < -0.9995 {
+                    let half: $Rot = self * $Rot::new(0.0, 0.0, 0.0, 1.0);
+                    let half: $Rot = (half - other * half.dot(other)).normalize();
+                    return if t < 0.5 {
+                        self.slerp_unflipped(half, 2.0 * t)
+                    } else {
+                        half.slerp_unflipped(other, 2.0 * t - 1.0)
+                    };
+                }
+
+                let theta: $t = math::$t::acos(cos);
+                let sin_inv: $t = 1.0 / math::$t::sin(theta);
+                self * (math::$t::sin((1.0 - t) * theta) * sin_inv) + other * (math::$t::sin(t * theta) * sin_inv)
+            }
+
+            // Spherical cubic interpolation from self to other, where the control
+            // rotors come from Rot::squad_control
+            pub fn squad(self, other: $Rot, control1: $Rot, control2: $Rot, t: $t) -> Self {
+                let outer: $Rot = self.slerp_unflipped(other, t);
+                let inner: $Rot = control1.slerp_unflipped(control2, t);
+                outer.slerp_unflipped(inner, 2.0 * t * (1.0 - t))
+            }
+
+            // Inner control rotor at the keyframe cur, given its neighbours
+            pub fn squad_control(prev: $Rot, cur: $Rot, next: $Rot) -> Self {
+                let prev: $Rot = if cur.dot(prev) < 0.0 { -prev } else { prev };
+                let next: $Rot = if cur.dot(next) < 0.0 { -next } else { next };
+                let cur_inv: $Rot = cur.reverse();
+                let log_next: $Bivec3 = (cur_inv * next).log();
+                let log_prev: $Bivec3 = (cur_inv * prev).log();
+                cur * ((log_next + log_prev) * -0.25).exp()
+            }
+        }
+
+        impl Add<$Rot> for $Rot {
+            type Output = Self;
+            fn add(self, val: Self) -> Self {
+                Self {
+                    a: self.a.add(val.a),
+                    b01: self.b01.add(val.b01),
+                    b02: self.b02.add(val.b02),
+                    b12: self.b12.add(val.b12),
+                }
+            }
+        }
+
+        impl Sub<$Rot> for $Rot {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self {
+                Self {
+                    a: self.a.sub(val.a),
+                    b01: self.b01.sub(val.b01),
+                    b02: self.b02.sub(val.b02),
+                    b12: self.b12.sub(val.b12),
+                }
+            }
+        }
+
+        impl Neg for $Rot {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    a: -self.a,
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b12: -self.b12,
+                }
+            }
+        }
+
+        impl Mul<$t> for $Rot {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    a: self.a.mul(val),
+                    b01: self.b01.mul(val),
+                    b02: self.b02.mul(val),
+                    b12: self.b12.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Rot {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    a: self.a.div(val),
+                    b01: self.b01.div(val),
+                    b02: self.b02.div(val),
+                    b12: self.b12.div(val),
+                }
+            }
+        }
+
+        // Geometric product, (p * q) applies q first and then p
+        impl Mul<$Rot> for $Rot {
+            type Output = Self;
+            fn mul(self, val: Self) -> Self {
+                let p: $Rot = self;
+                let q: $Rot = val;
+                Self {
+                    a: p.a * q.a - p.b01 * q.b01 - p.b02 * q.b02 - p.b12 * q.b12,
+                    b01: p.b01 * q.a + p.a * q.b01 + p.b12 * q.b02 - p.b02 * q.b12,
+                    b02: p.b02 * q.a + p.a * q.b02 - p.b12 * q.b01 + p.b01 * q.b12,
+                    b12: p.b12 * q.a + p.a * q.b12 + p.b02 * q.b01 - p.b01 * q.b02,
+                }
+            }
+        }
+
+        impl Mul<$Vec3> for $Rot {
+            type Output = $Vec3;
+            fn mul(self, vec: $Vec3) -> $Vec3 {
+                self.rotate(vec)
+            }
+        }
+
+        impl Index<usize> for $Rot {
+            type Output = $t;
+            fn index(&self, index: usize) -> &$t {
+                match index {
+                    0 => &self.a,
+                    1 => &self.b01,
+                    2 => &self.b02,
+                    3 => &self.b12,
+                    _ => panic!("index out of bounds: the len is 4 but the index is {}", index),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $Rot {
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                match index {
+                    0 => &mut self.a,
+                    1 => &mut self.b01,
+                    2 => &mut self.b02,
+                    3 => &mut self.b12,
+                    _ => panic!("index out of bounds: the len is 4 but the index is {}", index),
+                }
+            }
+        }
+
+        impl_ref_ops!($Rot;
+            Add<$Rot>, add;
+            Sub<$Rot>, sub;
+            Mul<$t>, mul;
+            Div<$t>, div;
+            Mul<$Rot>, mul;
+            Mul<$Vec3>, mul;
+        );
+
+        impl_assign_ops!($Rot;
+            AddAssign<$Rot>, add_assign, Add, add;
+            SubAssign<$Rot>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+            MulAssign<$Rot>, mul_assign, Mul, mul;
+        );
+
+        impl_scalar_lhs_mul!($Rot, $t);
+        impl_ref_neg!($Rot);
+    };
 }
 
-#[derive(Debug, Copy, Clone)]
-pub struct Rot {
-    pub a: f32,
-    pub b01: f32,
-    pub b02: f32,
-    pub b12: f32,
+macro_rules! impl_rot4 {
+    ($Rot4:ident, $t:ident, $Vec4:ident, $Mat4:ident, $Bivec4:ident, $Rot:ident, $rot4:ident) => {
+        #[allow(dead_code, clippy::too_many_arguments)]
+        const fn $rot4(a: $t, b01: $t, b02: $t, b03: $t, b12: $t, b13: $t, b23: $t, q0123: $t) -> $Rot4 {
+            $Rot4::new(a, b01, b02, b03, b12, b13, b23, q0123)
+        }
+
+        // 4D rotor, the even part of the 4D algebra. On top of the scalar and
+        // the six planes it needs the I ^ J ^ K ^ L part for double rotations,
+        // which turn two perpendicular planes at once. Applied as R v R† like
+        // Rot, and p * q applies q first.
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Rot4 {
+            pub a: $t,
+            pub b01: $t,
+            pub b02: $t,
+            pub b03: $t,
+            pub b12: $t,
+            pub b13: $t,
+            pub b23: $t,
+            pub q0123: $t,
+        }
+
+        impl $Rot4 {
+            #[allow(clippy::too_many_arguments)]
+            pub const fn new(a: $t, b01: $t, b02: $t, b03: $t, b12: $t, b13: $t, b23: $t, q0123: $t) -> Self {
+                Self { a, b01, b02, b03, b12, b13, b23, q0123 }
+            }
+
+            pub const fn from_array(array: [$t; 8]) -> Self {
+                Self::new(array[0], array[1], array[2], array[3], array[4], array[5], array[6], array[7])
+            }
+
+            pub const fn to_array(self) -> [$t; 8] {
+                [self.a, self.b01, self.b02, self.b03, self.b12, self.b13, self.b23, self.q0123]
+            }
+
+            pub fn as_array(&self) -> &[$t; 8] {
+                unsafe { &*(self as *const Self as *const [$t; 8]) }
+            }
+
+            // r and -r are the same rotation, so both count as equal
+            pub fn abs_diff_eq(self, other: $Rot4, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+                    || math::$t::abs_diff_eq(&self.to_array(), &(-other).to_array(), max_abs_diff)
+            }
+
+            // r and -r are the same rotation, so both count as equal
+            pub fn relative_eq(self, other: $Rot4, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+                    || math::$t::relative_eq(&self.to_array(), &(-other).to_array(), max_abs_diff, max_relative)
+            }
+
+            pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+            // Rotation by angle in the plane, going from the first vector of the
+            // plane towards the second. The plane should be the wedge of two
+            // vectors, see Bivec4::exp for anything else.
+            pub fn from_angle_plane(angle: $t, plane: $Bivec4) -> Self {
+                let plane: $Bivec4 = plane.normalize() * -math::$t::sin(angle * 0.5);
+                Self::new(
+                    math::$t::cos(angle * 0.5),
+                    plane.b01,
+                    plane.b02,
+                    plane.b03,
+                    plane.b12,
+                    plane.b13,
+                    plane.b23,
+                    0.0,
+                )
+            }
+
+            // 3D rotation of I, J, K that leaves L alone
+            pub fn from_rot(rot: $Rot) -> Self {
+                Self::new(rot.a, rot.b01, rot.b02, 0.0, rot.b12, 0.0, 0.0, 0.0)
+            }
+
+            pub fn bivector(self) -> $Bivec4 {
+                $Bivec4::new(self.b01, self.b02, self.b03, self.b12, self.b13, self.b23)
+            }
+
+            pub fn dot(self, other: $Rot4) -> $t {
+                self.a * other.a
+                    + self.b01 * other.b01 + self.b02 * other.b02 + self.b03 * other.b03
+                    + self.b12 * other.b12 + self.b13 * other.b13 + self.b23 * other.b23
+                    + self.q0123 * other.q0123
+            }
+
+            pub fn norm(self) -> $t {
+                math::$t::sqrt(self.norm_squared())
+            }
+
+            pub fn norm_squared(self) -> $t {
+                self.dot(self)
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+
+            // None if the norm is zero, infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            pub fn normalize_or_identity(self) -> Self {
+                self.try_normalize().unwrap_or(Self::IDENTITY)
+            }
+
+            // Zero is not a rotation, see Rot::normalize_or_zero
+            pub fn normalize_or_zero(self) -> Self {
+                self.try_normalize().unwrap_or(Self::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0))
+            }
+
+            // Checks the squared norm against 1
+            pub fn is_normalized(self, epsilon: $t) -> bool {
+                (self.norm_squared() - 1.0).abs() <= epsilon
+            }
+
+            pub fn reverse(self) -> Self {
+                Self {
+                    a: self.a,
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b03: -self.b03,
+                    b12: -self.b12,
+                    b13: -self.b13,
+                    b23: -self.b23,
+                    q0123: self.q0123,
+                }
+            }
+
+            // For unit rotors this is the same as the reverse
+            pub fn inverse(self) -> Self {
+                self.reverse() / self.norm_squared()
+            }
+
+            pub fn rotate(self, vec: $Vec4) -> $Vec4 {
+                let p: $Rot4 = self;
+
+                // q = R v, a vector and a trivector
+                let q: $Vec4 = $Vec4::new(
+                    p.a * vec.i + p.b01 * vec.j + p.b02 * vec.k + p.b03 * vec.l,
+                    p.a * vec.j - p.b01 * vec.i + p.b12 * vec.k + p.b13 * vec.l,
+                    p.a * vec.k - p.b02 * vec.i - p.b12 * vec.j + p.b23 * vec.l,
+                    p.a * vec.l - p.b03 * vec.i - p.b13 * vec.j - p.b23 * vec.k,
+                );
+                let q012: $t = p.b01 * vec.k - p.b02 * vec.j + p.b12 * vec.i + p.q0123 * vec.l;
+                let q013: $t = p.b01 * vec.l - p.b03 * vec.j + p.b13 * vec.i - p.q0123 * vec.k;
+                let q023: $t = p.b02 * vec.l - p.b03 * vec.k + p.b23 * vec.i + p.q0123 * vec.j;
+                let q123: $t = p.b12 * vec.l - p.b13 * vec.k + p.b23 * vec.j - p.q0123 * vec.i;
+
+                // q R†, the trivector part cancels out for unit rotors
+                $Vec4::new(
+                    q.i * p.a + q.j * p.b01 + q.k * p.b02 + q.l * p.b03
+                        + q012 * p.b12 + q013 * p.b13 + q023 * p.b23 + q123 * p.q0123,
+                    -q.i * p.b01 + q.j * p.a + q.k * p.b12 + q.l * p.b13
+                        - q012 * p.b02 - q013 * p.b03 - q023 * p.q0123 + q123 * p.b23,
+                    -q.i * p.b02 - q.j * p.b12 + q.k * p.a + q.l * p.b23
+                        + q012 * p.b01 + q013 * p.q0123 - q023 * p.b03 - q123 * p.b13,
+                    -q.i * p.b03 - q.j * p.b13 - q.k * p.b23 + q.l * p.a
+                        - q012 * p.q0123 + q013 * p.b01 + q023 * p.b02 + q123 * p.b12,
+                )
+            }
+
+            pub fn to_mat4(self) -> $Mat4 {
+                $Mat4::from_rot4(self)
+            }
+
+            // Normalized linear interpolation, takes the shortest arc
+            pub fn nlerp(self, other: $Rot4, t: $t) -> Self {
+                let other: $Rot4 = if self.dot(other) < 0.0 { -other } else { other };
+                (self * (1.0 - t) + other * t).normalize()
+            }
+        }
+
+        impl Add<$Rot4> for $Rot4 {
+            type Output = Self;
+            fn add(self, val: Self) -> Self {
+                Self {
+                    a: self.a.add(val.a),
+                    b01: self.b01.add(val.b01),
+                    b02: self.b02.add(val.b02),
+                    b03: self.b03.add(val.b03),
+                    b12: self.b12.add(val.b12),
+                    b13: self.b13.add(val.b13),
+                    b23: self.b23.add(val.b23),
+                    q0123: self.q0123.add(val.q0123),
+                }
+            }
+        }
+
+        impl Sub<$Rot4> for $Rot4 {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self {
+                Self {
+                    a: self.a.sub(val.a),
+                    b01: self.b01.sub(val.b01),
+                    b02: self.b02.sub(val.b02),
+                    b03: self.b03.sub(val.b03),
+                    b12: self.b12.sub(val.b12),
+                    b13: self.b13.sub(val.b13),
+                    b23: self.b23.sub(val.b23),
+                    q0123: self.q0123.sub(val.q0123),
+                }
+            }
+        }
+
+        impl Neg for $Rot4 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    a: -self.a,
+                    b01: -self.b01,
+                    b02: -self.b02,
+                    b03: -self.b03,
+                    b12: -self.b12,
+                    b13: -self.b13,
+                    b23: -self.b23,
+                    q0123: -self.q0123,
+                }
+            }
+        }
+
+        impl Mul<$t> for $Rot4 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    a: self.a.mul(val),
+                    b01: self.b01.mul(val),
+                    b02: self.b02.mul(val),
+                    b03: self.b03.mul(val),
+                    b12: self.b12.mul(val),
+                    b13: self.b13.mul(val),
+                    b23: self.b23.mul(val),
+                    q0123: self.q0123.mul(val),
+                }
+            }
+        }
+
+        impl Div<$t> for $Rot4 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    a: self.a.div(val),
+                    b01: self.b01.div(val),
+                    b02: self.b02.div(val),
+                    b03: self.b03.div(val),
+                    b12: self.b12.div(val),
+                    b13: self.b13.div(val),
+                    b23: self.b23.div(val),
+                    q0123: self.q0123.div(val),
+                }
+            }
+        }
+
+        // Geometric product, the I, J, K part is the same as Rot * Rot
+        impl Mul<$Rot4> for $Rot4 {
+            type Output = Self;
+            fn mul(self, val: Self) -> Self {
+                let p: $Rot4 = self;
+                let q: $Rot4 = val;
+                Self {
+                    a: p.a * q.a - p.b01 * q.b01 - p.b02 * q.b02 - p.b03 * q.b03
+                     - p.b12 * q.b12 - p.b13 * q.b13 - p.b23 * q.b23 + p.q0123 * q.q0123,
+                    b01: p.a * q.b01 + p.b01 * q.a - p.b02 * q.b12 - p.b03 * q.b13
+                       + p.b12 * q.b02 + p.b13 * q.b03 - p.b23 * q.q0123 - p.q0123 * q.b23,
+                    b02: p.a * q.b02 + p.b01 * q.b12 + p.b02 * q.a - p.b03 * q.b23
+                       - p.b12 * q.b01 + p.b13 * q.q0123 + p.b23 * q.b03 + p.q0123 * q.b13,
+                    b03: p.a * q.b03 + p.b01 * q.b13 + p.b02 * q.b23 + p.b03 * q.a
+                       - p.b12 * q.q0123 - p.b13 * q.b01 - p.b23 * q.b02 - p.q0123 * q.b12,
+                    b12: p.a * q.b12 - p.b01 * q.b02 + p.b02 * q.b01 - p.b03 * q.q0123
+                       + p.b12 * q.a - p.b13 * q.b23 + p.b23 * q.b13 - p.q0123 * q.b03,
+                    b13: p.a * q.b13 - p.b01 * q.b03 + p.b02 * q.q0123 + p.b03 * q.b01
+                       + p.b12 * q.b23 + p.b13 * q.a - p.b23 * q.b12 + p.q0123 * q.b02,
+                    b23: p.a * q.b23 - p.b01 * q.q0123 - p.b02 * q.b03 + p.b03 * q.b02
+                       - p.b12 * q.b13 + p.b13 * q.b12 + p.b23 * q.a - p.q0123 * q.b01,
+                    q0123: p.a * q.q0123 + p.b01 * q.b23 - p.b02 * q.b13 + p.b03 * q.b12
+                         + p.b12 * q.b03 - p.b13 * q.b02 + p.b23 * q.b01 + p.q0123 * q.a,
+                }
+            }
+        }
+
+        impl Mul<$Vec4> for $Rot4 {
+            type Output = $Vec4;
+            fn mul(self, vec: $Vec4) -> $Vec4 {
+                self.rotate(vec)
+            }
+        }
+
+        impl From<$Rot> for $Rot4 {
+            fn from(rot: $Rot) -> Self {
+                Self::from_rot(rot)
+            }
+        }
+
+        impl Index<usize> for $Rot4 {
+            type Output = $t;
+            fn index(&self, index: usize) -> &$t {
+                match index {
+                    0 => &self.a,
+                    1 => &self.b01,
+                    2 => &self.b02,
+                    3 => &self.b03,
+                    4 => &self.b12,
+                    5 => &self.b13,
+                    6 => &self.b23,
+                    7 => &self.q0123,
+                    _ => panic!("index out of bounds: the len is 8 but the index is {}", index),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $Rot4 {
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                match index {
+                    0 => &mut self.a,
+                    1 => &mut self.b01,
+                    2 => &mut self.b02,
+                    3 => &mut self.b03,
+                    4 => &mut self.b12,
+                    5 => &mut self.b13,
+                    6 => &mut self.b23,
+                    7 => &mut self.q0123,
+                    _ => panic!("index out of bounds: the len is 8 but the index is {}", index),
+                }
+            }
+        }
+
+        impl_ref_ops!($Rot4;
+            Add<$Rot4>, add;
+            Sub<$Rot4>, sub;
+            Mul<$Rot4>, mul;
+            Mul<$Vec4>, mul;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Rot4;
+            AddAssign<$Rot4>, add_assign, Add, add;
+            SubAssign<$Rot4>, sub_assign, Sub, sub;
+            MulAssign<$Rot4>, mul_assign, Mul, mul;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Rot4, $t);
+        impl_ref_neg!($Rot4);
+    };
 }
 
-impl Rot {
-    pub const fn new(a: f32, b01: f32, b02: f32, b12: f32) -> Self {
-        Self { a, b01, b02, b12 }
+impl_rot2!(Rot2, f32, Vec2, Mat2, Mat3, Bivec2, rot2);
+impl_rot2!(DRot2, f64, DVec2, DMat2, DMat3, DBivec2, drot2);
+
+impl_rot!(Rot, f32, Vec3, Mat3, Mat4, Bivec3, rot);
+impl_rot!(DRot, f64, DVec3, DMat3, DMat4, DBivec3, drot);
+
+impl_rot4!(Rot4, f32, Vec4, Mat4, Bivec4, Rot, rot4);
+impl_rot4!(DRot4, f64, DVec4, DMat4, DBivec4, DRot, drot4);
+
+impl Rot2 {
+    pub fn as_f64(self) -> DRot2 {
+        DRot2::new(self.a as f64, self.b01 as f64)
     }
+}
 
-    pub fn norm(self) -> f32 {
-        f32::sqrt(f32::powi(self.a, 2)
-                + f32::powi(self.b01, 2) 
-                + f32::powi(self.b02, 2) 
-                + f32::powi(self.b12, 2))
+impl DRot2 {
+    pub fn as_f32(self) -> Rot2 {
+        Rot2::new(self.a as f32, self.b01 as f32)
     }
+}
 
-    pub fn norm_squared(self) -> f32 {
-        f32::powi(self.a, 2)
-      + f32::powi(self.b01, 2) 
-      + f32::powi(self.b02, 2) 
-      + f32::powi(self.b12, 2) 
+impl Rot {
+    pub fn as_f64(self) -> DRot {
+        DRot::new(self.a as f64, self.b01 as f64, self.b02 as f64, self.b12 as f64)
     }
+}
 
-    // be careful, divide by zero error!
-    pub fn normalize(self) -> Self {
-        Self {
-            a: self.a / self.norm(),
-            b01: self.b01 / self.norm(),
-            b02: self.b02 / self.norm(),
-            b12: self.b12 / self.norm(),
-        }
+impl DRot {
+    pub fn as_f32(self) -> Rot {
+        Rot::new(self.a as f32, self.b01 as f32, self.b02 as f32, self.b12 as f32)
     }
+}
 
-    // Returns rotation from a vector to another
-    pub fn from_vecs(from: Vec3, to: Vec3) -> Self {
-        let wedge_prod: Bivec3 = Bivec3::wedge(from, to);
-        Self {
-            a: 1.0 + from * to,
-            b01: wedge_prod.b01,
-            b02: wedge_prod.b02,
-            b12: wedge_prod.b12,
-        }.normalize()
+impl Rot4 {
+    pub fn as_f64(self) -> DRot4 {
+        DRot4::from_array(self.to_array().map(|x| x as f64))
     }
 }
 
-// dot product
-impl Mul<Rot> for Rot {
-    type Output = f32;
-    fn mul(self, val: Self) -> f32 {
-        self.a * val.a + self.b01 * val.b01 + self.b02 * val.b02 + self.b12 * val.b12
+impl DRot4 {
+    pub fn as_f32(self) -> Rot4 {
+        Rot4::from_array(self.to_array().map(|x| x as f32))
     }
 }
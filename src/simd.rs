@@ -0,0 +1,65 @@
+use crate::{Vec4, Mat4};
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+
+// Vec4 is repr(C, align(16)) with the simd feature, so a pointer to a whole
+// Vec4 can be used for aligned loads and stores. It has to come from the Vec4
+// itself, a pointer from &vec.i only covers the first component.
+
+#[cfg(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2")))]
+pub(crate) fn mat4_mul_vec4(mat: &Mat4, vec: Vec4) -> Vec4 {
+    let mut out: Vec4 = Vec4::ZERO;
+    unsafe {
+        let col1: __m128 = _mm_load_ps(&mat.col1 as *const Vec4 as *const f32);
+        let col2: __m128 = _mm_load_ps(&mat.col2 as *const Vec4 as *const f32);
+        let col3: __m128 = _mm_load_ps(&mat.col3 as *const Vec4 as *const f32);
+        let col4: __m128 = _mm_load_ps(&mat.col4 as *const Vec4 as *const f32);
+        let sum: __m128 = _mm_add_ps(
+            _mm_add_ps(_mm_mul_ps(col1, _mm_set1_ps(vec.i)), _mm_mul_ps(col2, _mm_set1_ps(vec.j))),
+            _mm_add_ps(_mm_mul_ps(col3, _mm_set1_ps(vec.k)), _mm_mul_ps(col4, _mm_set1_ps(vec.l))),
+        );
+        _mm_store_ps(&mut out as *mut Vec4 as *mut f32, sum);
+    }
+    out
+}
+
+#[cfg(target_arch = "aarch64")]
+pub(crate) fn mat4_mul_vec4(mat: &Mat4, vec: Vec4) -> Vec4 {
+    let mut out: Vec4 = Vec4::ZERO;
+    unsafe {
+        let col1: float32x4_t = vld1q_f32(&mat.col1 as *const Vec4 as *const f32);
+        let col2: float32x4_t = vld1q_f32(&mat.col2 as *const Vec4 as *const f32);
+        let col3: float32x4_t = vld1q_f32(&mat.col3 as *const Vec4 as *const f32);
+        let col4: float32x4_t = vld1q_f32(&mat.col4 as *const Vec4 as *const f32);
+        let sum: float32x4_t = vaddq_f32(
+            vaddq_f32(vmulq_n_f32(col1, vec.i), vmulq_n_f32(col2, vec.j)),
+            vaddq_f32(vmulq_n_f32(col3, vec.k), vmulq_n_f32(col4, vec.l)),
+        );
+        vst1q_f32(&mut out as *mut Vec4 as *mut f32, sum);
+    }
+    out
+}
+
+// Scalar fallback for everything else
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2"),
+    target_arch = "aarch64",
+)))]
+pub(crate) fn mat4_mul_vec4(mat: &Mat4, vec: Vec4) -> Vec4 {
+    mat.col1 * vec.i + mat.col2 * vec.j + mat.col3 * vec.k + mat.col4 * vec.l
+}
+
+pub(crate) fn mat4_mul_mat4(lhs: &Mat4, rhs: &Mat4) -> Mat4 {
+    Mat4::from_cols(
+        mat4_mul_vec4(lhs, rhs.col1),
+        mat4_mul_vec4(lhs, rhs.col2),
+        mat4_mul_vec4(lhs, rhs.col3),
+        mat4_mul_vec4(lhs, rhs.col4),
+    )
+}
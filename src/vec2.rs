@@ -1,152 +1,395 @@
+use crate::math;
+use crate::impl_ops::*;
 use core::{f32, ops::*};
 
-const fn vec2(i: f32, j: f32) -> Vec2 {
-    Vec2::new(i, j)
-}
+macro_rules! impl_vec2 {
+    ($Vec2:ident, $t:ident, $vec2:ident) => {
+        #[allow(dead_code)]
+        const fn $vec2(i: $t, j: $t) -> $Vec2 {
+            $Vec2::new(i, j)
+        }
 
-#[derive(Debug, Copy, Clone)]
-pub struct Vec2 {
-    pub i: f32,
-    pub j: f32,
-}
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Vec2 {
+            pub i: $t,
+            pub j: $t,
+        }
 
-impl Vec2 {
-    pub const fn new(i: f32, j: f32) -> Self{
-        Self {i, j}
-    }
+        impl $Vec2 {
+            pub const fn new(i: $t, j: $t) -> Self{
+                Self {i, j}
+            }
 
-    pub const ZERO: Self = Self::new(0.0, 0.0);
+            pub const fn from_array(array: [$t; 2]) -> Self {
+                Self::new(array[0], array[1])
+            }
 
-    pub const ONE: Self = Self::new(1.0, 1.0);
+            pub const fn to_array(self) -> [$t; 2] {
+                [self.i, self.j]
+            }
 
-    pub const ONE_NEG: Self = Self::new(-1.0, -1.0);
+            pub fn as_array(&self) -> &[$t; 2] {
+                // Fine since the struct is repr(C) and only holds floats
+                unsafe { &*(self as *const Self as *const [$t; 2]) }
+            }
 
-    pub const I: Self = Self::new(1.0, 0.0);
+            pub fn abs_diff_eq(self, other: $Vec2, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+            }
 
-    pub const J: Self = Self::new(0.0, 1.0);
+            pub fn relative_eq(self, other: $Vec2, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+            }
 
-    pub const I_NEG: Self = Self::new(-1.0, 0.0);
+            pub const ZERO: Self = Self::new(0.0, 0.0);
 
-    pub const J_NEG: Self = Self::new(0.0, -1.0);
+            pub const NAN: Self = Self::new($t::NAN, $t::NAN);
 
-    pub fn norm(self) -> f32 {
-        f32::sqrt(f32::powi(self.i, 2) 
-                + f32::powi(self.j, 2))
-    }
+            pub const ONE: Self = Self::new(1.0, 1.0);
 
-    pub fn norm_squared(self) -> f32 {
-        f32::powi(self.i, 2) 
-      + f32::powi(self.j, 2) 
-    }
+            pub const ONE_NEG: Self = Self::new(-1.0, -1.0);
+
+            pub const I: Self = Self::new(1.0, 0.0);
+
+            pub const J: Self = Self::new(0.0, 1.0);
+
+            pub const I_NEG: Self = Self::new(-1.0, 0.0);
+
+            pub const J_NEG: Self = Self::new(0.0, -1.0);
+
+            pub fn norm(self) -> $t {
+                math::$t::sqrt(math::$t::powi(self.i, 2) 
+                        + math::$t::powi(self.j, 2))
+            }
+
+            pub fn norm_squared(self) -> $t {
+                math::$t::powi(self.i, 2) 
+              + math::$t::powi(self.j, 2) 
+            }
+
+            pub fn abs(self) -> Self {
+                Self {
+                    i: $t::abs(self.i),
+                    j: $t::abs(self.j),
+                }
+            }
+
+            pub fn dist(self, other: $Vec2) -> $t {
+                (self - other).norm()
+            }
+
+            pub fn dist_squared(self, other: $Vec2) -> $t{
+                (self - other).norm_squared()
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
 
-    pub fn abs(self) -> Self {
-        Self {
-            i: f32::abs(self.i),
-            j: f32::abs(self.j),
+            // None if the norm is zero, infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            pub fn normalize_or(self, fallback: $Vec2) -> Self {
+                self.try_normalize().unwrap_or(fallback)
+            }
+
+            pub fn normalize_or_zero(self) -> Self {
+                self.normalize_or(Self::ZERO)
+            }
+
+            // Checks the squared norm against 1
+            pub fn is_normalized(self, epsilon: $t) -> bool {
+                (self.norm_squared() - 1.0).abs() <= epsilon
+            }
+
+            pub fn normalize_or_nan(self) -> Self {
+                self.normalize_or(Self::NAN)
+            }
+
+            pub fn midpoint(self, other: $Vec2) -> Self {
+                (self + other) * 0.5
+            }
+
+            // t = 0 gives self and t = 1 gives other
+            pub fn lerp(self, other: $Vec2, t: $t) -> Self {
+                self + (other - self) * t
+            }
+
+            // Mirrors self about the plane with the given normal, which
+            // should be normalized
+            pub fn reflect(self, normal: $Vec2) -> Self {
+                self - normal * (2.0 * (self * normal))
+            }
+
+            // Bends self through a surface with the given normal, eta being
+            // the ratio of the refractive indices. Both self and normal should
+            // be normalized. Returns ZERO on total internal reflection.
+            pub fn refract(self, normal: $Vec2, eta: $t) -> Self {
+                let cos: $t = self * normal;
+                let k: $t = 1.0 - eta * eta * (1.0 - cos * cos);
+                if k < 0.0 {
+                    Self::ZERO
+                } else {
+                    self * eta - normal * (eta * cos + math::$t::sqrt(k))
+                }
+            }
+
+            // be careful, divide by zero error!
+            pub fn project_onto(self, other: $Vec2) -> Self {
+                other * ((self * other) / other.norm_squared())
+            }
+
+            // The part of self perpendicular to other
+            // be careful, divide by zero error!
+            pub fn reject_from(self, other: $Vec2) -> Self {
+                self - self.project_onto(other)
+            }
+
+            // In radians, between 0 and pi
+            pub fn angle_between(self, other: $Vec2) -> $t {
+                let cos: $t = (self * other) / math::$t::sqrt(self.norm_squared() * other.norm_squared());
+                math::$t::acos(cos.clamp(-1.0, 1.0))
+            }
+
+            // A zero vector has no direction to stretch along, so it is returned
+            // as is even when min is above zero
+            pub fn clamp_length(self, min: $t, max: $t) -> Self {
+                let norm: $t = self.norm();
+                if norm == 0.0 {
+                    self
+                } else if norm < min {
+                    self * (min / norm)
+                } else if norm > max {
+                    self * (max / norm)
+                } else {
+                    self
+                }
+            }
+
+            pub fn min(self, other: $Vec2) -> Self {
+                Self {
+                    i: self.i.min(other.i),
+                    j: self.j.min(other.j),
+                }
+            }
+
+            pub fn max(self, other: $Vec2) -> Self {
+                Self {
+                    i: self.i.max(other.i),
+                    j: self.j.max(other.j),
+                }
+            }
+
+            // Component-wise, min should not be greater than max
+            pub fn clamp(self, min: $Vec2, max: $Vec2) -> Self {
+                self.max(min).min(max)
+            }
+
+            pub fn floor(self) -> Self {
+                Self {
+                    i: math::$t::floor(self.i),
+                    j: math::$t::floor(self.j),
+                }
+            }
+
+            pub fn ceil(self) -> Self {
+                Self {
+                    i: math::$t::ceil(self.i),
+                    j: math::$t::ceil(self.j),
+                }
+            }
+
+            pub fn round(self) -> Self {
+                Self {
+                    i: math::$t::round(self.i),
+                    j: math::$t::round(self.j),
+                }
+            }
+
+            pub fn signum(self) -> Self {
+                Self {
+                    i: self.i.signum(),
+                    j: self.j.signum(),
+                }
+            }
+
+            pub fn min_element(self) -> $t {
+                self.i.min(self.j)
+            }
+
+            pub fn max_element(self) -> $t {
+                self.i.max(self.j)
+            }
+
+            pub fn is_finite(self) -> bool {
+                self.i.is_finite() && self.j.is_finite()
+            }
+
+            pub fn is_nan(self) -> bool {
+                self.i.is_nan() || self.j.is_nan()
+            }
+
+            // Component-wise product
+            pub fn mul_elem(self, other: $Vec2) -> Self {
+                Self {
+                    i: self.i * other.i,
+                    j: self.j * other.j,
+                }
+            }
+
+            // Component-wise quotient
+            pub fn div_elem(self, other: $Vec2) -> Self {
+                Self {
+                    i: self.i / other.i,
+                    j: self.j / other.j,
+                }
+            }
         }
-    }
 
-    pub fn dist(self, other: Vec2) -> f32 {
-        (self - other).norm()
-    }
+        // Operation Traits
+        impl Add<$Vec2> for $Vec2 {
+            type Output = Self;
+            fn add(self, val: Self) -> Self{
+                Self {
+                    i: self.i.add(val.i),
+                    j: self.j.add(val.j),
+                }
+            }
+        }
 
-    pub fn dist_squared(self, other: Vec2) -> f32{
-        (self - other).norm_squared()
-    }
+        impl Add<$t> for $Vec2 {
+            type Output = Self;
+            fn add(self, val: $t) -> Self{
+                Self {
+                    i: self.i.add(val),
+                    j: self.j.add(val),
+                }
+            }
+        }
 
-    // be careful, divide by zero error!
-    pub fn normalize(self) -> Self {
-        Self {
-            i: self.i / self.norm(),
-            j: self.j / self.norm(),
+        impl Sub<$Vec2> for $Vec2 {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self{
+                Self {
+                    i: self.i.sub(val.i),
+                    j: self.j.sub(val.j),
+                }
+            }
         }
-    }
 
-    pub fn normalize_or_nan(self) -> Self {
-        if self.norm() > 0.0 {
-            Self {
-                i: self.i / self.norm(),
-                j: self.j / self.norm(),
+        impl Sub<$t> for $Vec2 {
+            type Output = Self;
+            fn sub(self, val: $t) -> Self{
+                Self {
+                    i: self.i.sub(val),
+                    j: self.j.sub(val),
+                }
             }
-        } else {
-            Self{
-                i: f32::NAN,
-                j: f32::NAN,
+        }
+
+        // Vector dot product
+        impl Mul<$Vec2> for $Vec2 {
+            type Output = $t;
+            fn mul(self, val: Self) -> $t {
+                self.i * val.i + self.j * val.j
             }
         }
-    }
 
-    pub fn midpoint(self, other: Vec2) -> Self {
-        (self + other) * 0.5
-    }
-}
+        impl Mul<$t> for $Vec2 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    i: self.i.mul(val),
+                    j: self.j.mul(val),
+                }
+            }
+        }
 
-// Operation Traits
-impl Add<Vec2> for Vec2 {
-    type Output = Self;
-    fn add(self, val: Self) -> Self{
-        Self {
-            i: self.i.add(val.i),
-            j: self.j.add(val.j),
+        impl Div<$t> for $Vec2 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    i: self.i.div(val),
+                    j: self.j.div(val),
+                }
+            }
         }
-    }
-}
 
-impl Add<f32> for Vec2 {
-    type Output = Self;
-    fn add(self, val: f32) -> Self{
-        Self {
-            i: self.i.add(val),
-            j: self.j.add(val),
+        impl Neg for $Vec2 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    i: -self.i,
+                    j: -self.j,
+                }
+            }
         }
-    }
-}
 
-impl Sub<Vec2> for Vec2 {
-    type Output = Self;
-    fn sub(self, val: Self) -> Self{
-        Self {
-            i: self.i.sub(val.i),
-            j: self.j.sub(val.j),
+        impl Index<usize> for $Vec2 {
+            type Output = $t;
+            fn index(&self, index: usize) -> &$t {
+                match index {
+                    0 => &self.i,
+                    1 => &self.j,
+                    _ => panic!("index out of bounds: the len is 2 but the index is {}", index),
+                }
+            }
         }
-    }
-}
 
-impl Sub<f32> for Vec2 {
-    type Output = Self;
-    fn sub(self, val: f32) -> Self{
-        Self {
-            i: self.i.sub(val),
-            j: self.j.sub(val),
+        impl IndexMut<usize> for $Vec2 {
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                match index {
+                    0 => &mut self.i,
+                    1 => &mut self.j,
+                    _ => panic!("index out of bounds: the len is 2 but the index is {}", index),
+                }
+            }
         }
-    }
-}
 
-// Vector dot product
-impl Mul<Vec2> for Vec2 {
-    type Output = f32;
-    fn mul(self, val: Self) -> f32 {
-        self.i * val.i + self.j * val.j
-    }
+        impl_ref_ops!($Vec2;
+            Add<$Vec2>, add;
+            Add<$t>, add;
+            Sub<$Vec2>, sub;
+            Sub<$t>, sub;
+            Mul<$Vec2>, mul;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Vec2;
+            AddAssign<$Vec2>, add_assign, Add, add;
+            AddAssign<$t>, add_assign, Add, add;
+            SubAssign<$Vec2>, sub_assign, Sub, sub;
+            SubAssign<$t>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Vec2, $t);
+        impl_ref_neg!($Vec2);
+    };
 }
 
-impl Mul<f32> for Vec2 {
-    type Output = Self;
-    fn mul(self, val: f32) -> Self {
-        Self {
-            i: self.i.mul(val),
-            j: self.j.mul(val),
-        }
+impl_vec2!(Vec2, f32, vec2);
+impl_vec2!(DVec2, f64, dvec2);
+
+impl Vec2 {
+    pub fn as_f64(self) -> DVec2 {
+        DVec2::new(self.i as f64, self.j as f64)
     }
 }
 
-impl Div<f32> for Vec2 {
-    type Output = Self;
-    fn div(self, val: f32) -> Self {
-        Self {
-            i: self.i.div(val),
-            j: self.j.div(val),
-        }
+impl DVec2 {
+    pub fn as_f32(self) -> Vec2 {
+        Vec2::new(self.i as f32, self.j as f32)
     }
 }
-
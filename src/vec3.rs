@@ -1,177 +1,437 @@
+use crate::math;
+use crate::impl_ops::*;
 use core::{f32, ops::*};
 
-const fn vec3(i: f32, j: f32, k: f32) -> Vec3 {
-    Vec3::new(i, j, k)
-}
+macro_rules! impl_vec3 {
+    ($Vec3:ident, $t:ident, $vec3:ident) => {
+        #[allow(dead_code)]
+        const fn $vec3(i: $t, j: $t, k: $t) -> $Vec3 {
+            $Vec3::new(i, j, k)
+        }
 
-#[derive(Debug, Copy, Clone)]
-pub struct Vec3 {
-    pub i: f32,
-    pub j: f32,
-    pub k: f32,
-}
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $Vec3 {
+            pub i: $t,
+            pub j: $t,
+            pub k: $t,
+        }
 
-impl Vec3 {
-    pub const fn new(i: f32, j: f32, k: f32) -> Self{
-        Self {i, j, k}
-    }
+        impl $Vec3 {
+            pub const fn new(i: $t, j: $t, k: $t) -> Self{
+                Self {i, j, k}
+            }
 
-    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+            pub const fn from_array(array: [$t; 3]) -> Self {
+                Self::new(array[0], array[1], array[2])
+            }
 
-    pub const ONE: Self = Self::new(1.0, 1.0, 1.0);
+            pub const fn to_array(self) -> [$t; 3] {
+                [self.i, self.j, self.k]
+            }
 
-    pub const ONE_NEG: Self = Self::new(-1.0, -1.0, -1.0);
+            pub fn as_array(&self) -> &[$t; 3] {
+                unsafe { &*(self as *const Self as *const [$t; 3]) }
+            }
 
-    pub const I: Self = Self::new(1.0, 0.0, 0.0);
+            pub fn abs_diff_eq(self, other: $Vec3, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+            }
 
-    pub const J: Self = Self::new(0.0, 1.0, 0.0);
+            pub fn relative_eq(self, other: $Vec3, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+            }
 
-    pub const K: Self = Self::new(0.0, 0.0, 1.0);
+            pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
 
-    pub const I_NEG: Self = Self::new(-1.0, 0.0, 0.0);
+            pub const NAN: Self = Self::new($t::NAN, $t::NAN, $t::NAN);
 
-    pub const J_NEG: Self = Self::new(0.0, -1.0, 0.0);
+            pub const ONE: Self = Self::new(1.0, 1.0, 1.0);
 
-    pub const K_NEG: Self = Self::new(0.0, 0.0, -1.0);
+            pub const ONE_NEG: Self = Self::new(-1.0, -1.0, -1.0);
 
-    pub fn norm(self) -> f32 {
-        f32::sqrt(f32::powi(self.i, 2) 
-                + f32::powi(self.j, 2) 
-                + f32::powi(self.k, 2))
-    }
+            pub const I: Self = Self::new(1.0, 0.0, 0.0);
 
-    pub fn norm_squared(self) -> f32 {
-        f32::powi(self.i, 2) 
-      + f32::powi(self.j, 2) 
-      + f32::powi(self.k, 2) 
-    }
+            pub const J: Self = Self::new(0.0, 1.0, 0.0);
 
-    pub fn abs(self) -> Self {
-        Self {
-            i: f32::abs(self.i),
-            j: f32::abs(self.j),
-            k: f32::abs(self.k),
-        }
-    }
+            pub const K: Self = Self::new(0.0, 0.0, 1.0);
+
+            pub const I_NEG: Self = Self::new(-1.0, 0.0, 0.0);
+
+            pub const J_NEG: Self = Self::new(0.0, -1.0, 0.0);
+
+            pub const K_NEG: Self = Self::new(0.0, 0.0, -1.0);
+
+            pub fn norm(self) -> $t {
+                math::$t::sqrt(math::$t::powi(self.i, 2) 
+                        + math::$t::powi(self.j, 2) 
+                        + math::$t::powi(self.k, 2))
+            }
+
+            pub fn norm_squared(self) -> $t {
+                math::$t::powi(self.i, 2) 
+              + math::$t::powi(self.j, 2) 
+              + math::$t::powi(self.k, 2) 
+            }
+
+            pub fn abs(self) -> Self {
+                Self {
+                    i: $t::abs(self.i),
+                    j: $t::abs(self.j),
+                    k: $t::abs(self.k),
+                }
+            }
+
+            pub fn cross(self, other: $Vec3) -> $Vec3 {
+                Self {
+                    i: self.j * other.k - self.k * other.j,
+                    j: self.k * other.i - self.i * other.k,
+                    k: self.i * other.j - self.j * other.i,
+                }
+            }
+
+            // Some vector perpendicular to self, not normalized. Crosses with
+            // whichever axis keeps the result away from zero.
+            pub fn any_orthogonal(self) -> Self {
+                if self.i.abs() > self.k.abs() {
+                    Self::new(-self.j, self.i, 0.0)
+                } else {
+                    Self::new(0.0, -self.k, self.j)
+                }
+            }
+
+            pub fn dist(self, other: $Vec3) -> $t {
+                (self - other).norm()
+            }
+
+            pub fn dist_squared(self, other: $Vec3) -> $t{
+                (self - other).norm_squared()
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+
+            // None if the norm is zero, infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            pub fn normalize_or(self, fallback: $Vec3) -> Self {
+                self.try_normalize().unwrap_or(fallback)
+            }
+
+            pub fn normalize_or_zero(self) -> Self {
+                self.normalize_or(Self::ZERO)
+            }
+
+            // Checks the squared norm against 1
+            pub fn is_normalized(self, epsilon: $t) -> bool {
+                (self.norm_squared() - 1.0).abs() <= epsilon
+            }
+
+            pub fn normalize_or_nan(self) -> Self {
+                self.normalize_or(Self::NAN)
+            }
+
+            pub fn midpoint(self, other: $Vec3) -> Self {
+                (self + other) * 0.5
+            }
+
+            // t = 0 gives self and t = 1 gives other
+            pub fn lerp(self, other: $Vec3, t: $t) -> Self {
+                self + (other - self) * t
+            }
+
+            // Mirrors self about the plane with the given normal, which
+            // should be normalized
+            pub fn reflect(self, normal: $Vec3) -> Self {
+                self - normal * (2.0 * (self * normal))
+            }
+
+            // Bends self through a surface with the given normal, eta being
+            // the ratio of the refractive indices. Both self and normal should
+            // be normalized. Returns ZERO on total internal reflection.
+            pub fn refract(self, normal: $Vec3, eta: $t) -> Self {
+                let cos: $t = self * normal;
+                let k: $t = 1.0 - eta * eta * (1.0 - cos * cos);
+                if k < 0.0 {
+                    Self::ZERO
+                } else {
+                    self * eta - normal * (eta * cos + math::$t::sqrt(k))
+                }
+            }
+
+            // be careful, divide by zero error!
+            pub fn project_onto(self, other: $Vec3) -> Self {
+                other * ((self * other) / other.norm_squared())
+            }
+
+            // The part of self perpendicular to other
+            // be careful, divide by zero error!
+            pub fn reject_from(self, other: $Vec3) -> Self {
+                self - self.project_onto(other)
+            }
+
+            // In radians, between 0 and pi
+            pub fn angle_between(self, other: $Vec3) -> $t {
+                let cos: $t = (self * other) / math::$t::sqrt(self.norm_squared() * other.norm_squared());
+                math::$t::acos(cos.clamp(-1.0, 1.0))
+            }
+
+            // A zero vector has no direction to stretch along, so it is returned
+            // as is even when min is above zero
+            pub fn clamp_length(self, min: $t, max: $t) -> Self {
+                let norm: $t = self.norm();
+                if norm == 0.0 {
+                    self
+                } else if norm < min {
+                    self * (min / norm)
+                } else if norm > max {
+                    self * (max / norm)
+                } else {
+                    self
+                }
+            }
 
-    pub fn cross(self, other: Vec3) -> Vec3 {
-        Self {
-            i: self.j * other.k - self.k * other.j,
-            j: self.i * other.k - self.k * other.i,
-            k: self.i * other.j - self.j * other.i,
+            pub fn min(self, other: $Vec3) -> Self {
+                Self {
+                    i: self.i.min(other.i),
+                    j: self.j.min(other.j),
+                    k: self.k.min(other.k),
+                }
+            }
+
+            pub fn max(self, other: $Vec3) -> Self {
+                Self {
+                    i: self.i.max(other.i),
+                    j: self.j.max(other.j),
+                    k: self.k.max(other.k),
+                }
+            }
+
+            // Component-wise, min should not be greater than max
+            pub fn clamp(self, min: $Vec3, max: $Vec3) -> Self {
+                self.max(min).min(max)
+            }
+
+            pub fn floor(self) -> Self {
+                Self {
+                    i: math::$t::floor(self.i),
+                    j: math::$t::floor(self.j),
+                    k: math::$t::floor(self.k),
+                }
+            }
+
+            pub fn ceil(self) -> Self {
+                Self {
+                    i: math::$t::ceil(self.i),
+                    j: math::$t::ceil(self.j),
+                    k: math::$t::ceil(self.k),
+                }
+            }
+
+            pub fn round(self) -> Self {
+                Self {
+                    i: math::$t::round(self.i),
+                    j: math::$t::round(self.j),
+                    k: math::$t::round(self.k),
+                }
+            }
+
+            pub fn signum(self) -> Self {
+                Self {
+                    i: self.i.signum(),
+                    j: self.j.signum(),
+                    k: self.k.signum(),
+                }
+            }
+
+            pub fn min_element(self) -> $t {
+                self.i.min(self.j).min(self.k)
+            }
+
+            pub fn max_element(self) -> $t {
+                self.i.max(self.j).max(self.k)
+            }
+
+            pub fn is_finite(self) -> bool {
+                self.i.is_finite() && self.j.is_finite() && self.k.is_finite()
+            }
+
+            pub fn is_nan(self) -> bool {
+                self.i.is_nan() || self.j.is_nan() || self.k.is_nan()
+            }
+
+            // Component-wise product
+            pub fn mul_elem(self, other: $Vec3) -> Self {
+                Self {
+                    i: self.i * other.i,
+                    j: self.j * other.j,
+                    k: self.k * other.k,
+                }
+            }
+
+            // Component-wise quotient
+            pub fn div_elem(self, other: $Vec3) -> Self {
+                Self {
+                    i: self.i / other.i,
+                    j: self.j / other.j,
+                    k: self.k / other.k,
+                }
+            }
         }
-    }
 
-    pub fn dist(self, other: Vec3) -> f32 {
-        (self - other).norm()
-    }
+        // Operation Traits
+        impl Add<$Vec3> for $Vec3 {
+            type Output = Self;
+            fn add(self, val: Self) -> Self{
+                Self {
+                    i: self.i.add(val.i),
+                    j: self.j.add(val.j),
+                    k: self.k.add(val.k),
+                }
+            }
+        }
 
-    pub fn dist_squared(self, other: Vec3) -> f32{
-        (self - other).norm_squared()
-    }
+        impl Add<$t> for $Vec3 {
+            type Output = Self;
+            fn add(self, val: $t) -> Self{
+                Self {
+                    i: self.i.add(val),
+                    j: self.j.add(val),
+                    k: self.k.add(val),
+                }
+            }
+        }
 
-    // be careful, divide by zero error!
-    pub fn normalize(self) -> Self {
-        Self {
-            i: self.i / self.norm(),
-            j: self.j / self.norm(),
-            k: self.k / self.norm(),
+        impl Sub<$Vec3> for $Vec3 {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self{
+                Self {
+                    i: self.i.sub(val.i),
+                    j: self.j.sub(val.j),
+                    k: self.k.sub(val.k),
+                }
+            }
         }
-    }
 
-    pub fn normalize_or_nan(self) -> Self {
-        if self.norm() > 0.0 {
-            Self {
-                i: self.i / self.norm(),
-                j: self.j / self.norm(),
-                k: self.k / self.norm(),
+        impl Sub<$t> for $Vec3 {
+            type Output = Self;
+            fn sub(self, val: $t) -> Self{
+                Self {
+                    i: self.i.sub(val),
+                    j: self.j.sub(val),
+                    k: self.k.sub(val),
+                }
             }
-        } else {
-            Self{
-                i: f32::NAN,
-                j: f32::NAN,
-                k: f32::NAN,
+        }
+
+        // Vector dot product
+        impl Mul<$Vec3> for $Vec3 {
+            type Output = $t;
+            fn mul(self, val: Self) -> $t {
+                self.i * val.i + self.j * val.j + self.k * val.k
             }
         }
-    }
 
-    pub fn midpoint(self, other: Vec3) -> Self {
-        (self + other) * 0.5
-    }
-}
+        impl Mul<$t> for $Vec3 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    i: self.i.mul(val),
+                    j: self.j.mul(val),
+                    k: self.k.mul(val),
+                }
+            }
+        }
 
-// Operation Traits
-impl Add<Vec3> for Vec3 {
-    type Output = Self;
-    fn add(self, val: Self) -> Self{
-        Self {
-            i: self.i.add(val.i),
-            j: self.j.add(val.j),
-            k: self.k.add(val.k),
+        impl Div<$t> for $Vec3 {
+            type Output = Self;
+            fn div(self, val: $t) -> Self {
+                Self {
+                    i: self.i.div(val),
+                    j: self.j.div(val),
+                    k: self.k.div(val),
+                }
+            }
         }
-    }
-}
 
-impl Add<f32> for Vec3 {
-    type Output = Self;
-    fn add(self, val: f32) -> Self{
-        Self {
-            i: self.i.add(val),
-            j: self.j.add(val),
-            k: self.k.add(val),
+        impl Neg for $Vec3 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    i: -self.i,
+                    j: -self.j,
+                    k: -self.k,
+                }
+            }
         }
-    }
-}
 
-impl Sub<Vec3> for Vec3 {
-    type Output = Self;
-    fn sub(self, val: Self) -> Self{
-        Self {
-            i: self.i.sub(val.i),
-            j: self.j.sub(val.j),
-            k: self.k.sub(val.k),
+        impl Index<usize> for $Vec3 {
+            type Output = $t;
+            fn index(&self, index: usize) -> &$t {
+                match index {
+                    0 => &self.i,
+                    1 => &self.j,
+                    2 => &self.k,
+                    _ => panic!("index out of bounds: the len is 3 but the index is {}", index),
+                }
+            }
         }
-    }
-}
 
-impl Sub<f32> for Vec3 {
-    type Output = Self;
-    fn sub(self, val: f32) -> Self{
-        Self {
-            i: self.i.sub(val),
-            j: self.j.sub(val),
-            k: self.k.sub(val),
+        impl IndexMut<usize> for $Vec3 {
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                match index {
+                    0 => &mut self.i,
+                    1 => &mut self.j,
+                    2 => &mut self.k,
+                    _ => panic!("index out of bounds: the len is 3 but the index is {}", index),
+                }
+            }
         }
-    }
-}
 
-// Vector dot product
-impl Mul<Vec3> for Vec3 {
-    type Output = f32;
-    fn mul(self, val: Self) -> f32 {
-        self.i * val.i + self.j * val.j + self.k * val.k
-    }
+        impl_ref_ops!($Vec3;
+            Add<$Vec3>, add;
+            Add<$t>, add;
+            Sub<$Vec3>, sub;
+            Sub<$t>, sub;
+            Mul<$Vec3>, mul;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Vec3;
+            AddAssign<$Vec3>, add_assign, Add, add;
+            AddAssign<$t>, add_assign, Add, add;
+            SubAssign<$Vec3>, sub_assign, Sub, sub;
+            SubAssign<$t>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Vec3, $t);
+        impl_ref_neg!($Vec3);
+    };
 }
 
-impl Mul<f32> for Vec3 {
-    type Output = Self;
-    fn mul(self, val: f32) -> Self {
-        Self {
-            i: self.i.mul(val),
-            j: self.j.mul(val),
-            k: self.k.mul(val),
-        }
+impl_vec3!(Vec3, f32, vec3);
+impl_vec3!(DVec3, f64, dvec3);
+
+impl Vec3 {
+    pub fn as_f64(self) -> DVec3 {
+        DVec3::new(self.i as f64, self.j as f64, self.k as f64)
     }
 }
 
-impl Div<f32> for Vec3 {
-    type Output = Self;
-    fn div(self, val: f32) -> Self {
-        Self {
-            i: self.i.div(val),
-            j: self.j.div(val),
-            k: self.k.div(val),
-        }
+impl DVec3 {
+    pub fn as_f32(self) -> Vec3 {
+        Vec3::new(self.i as f32, self.j as f32, self.k as f32)
     }
 }
-
@@ -1,188 +1,446 @@
+use crate::math;
+use crate::impl_ops::*;
 use core::{f32, ops::*};
 
-const fn vec4(i: f32, j: f32, k: f32, l: f32) -> Vec4 {
-    Vec4::new(i, j, k, l)
-}
+macro_rules! impl_vec4 {
+    ($(#[$meta:meta])* $Vec4:ident, $t:ident, $vec4:ident) => {
+        #[allow(dead_code)]
+        const fn $vec4(i: $t, j: $t, k: $t, l: $t) -> $Vec4 {
+            $Vec4::new(i, j, k, l)
+        }
 
-#[derive(Debug, Copy, Clone)]
-pub struct Vec4 {
-    pub i: f32,
-    pub j: f32,
-    pub k: f32,
-    pub l: f32,
-}
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        $(#[$meta])*
+        pub struct $Vec4 {
+            pub i: $t,
+            pub j: $t,
+            pub k: $t,
+            pub l: $t,
+        }
 
-impl Vec4 {
-    pub const fn new(i: f32, j: f32, k: f32, l: f32) -> Self{
-        Self {i, j, k, l}
-    }
+        impl $Vec4 {
+            pub const fn new(i: $t, j: $t, k: $t, l: $t) -> Self{
+                Self {i, j, k, l}
+            }
 
-    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+            pub const fn from_array(array: [$t; 4]) -> Self {
+                Self::new(array[0], array[1], array[2], array[3])
+            }
 
-    pub const ONE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+            pub const fn to_array(self) -> [$t; 4] {
+                [self.i, self.j, self.k, self.l]
+            }
 
-    pub const ONE_NEG: Self = Self::new(-1.0, -1.0, -1.0, -1.0);
+            pub fn as_array(&self) -> &[$t; 4] {
+                unsafe { &*(self as *const Self as *const [$t; 4]) }
+            }
 
-    pub const I: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+            pub fn abs_diff_eq(self, other: $Vec4, max_abs_diff: $t) -> bool {
+                math::$t::abs_diff_eq(&self.to_array(), &other.to_array(), max_abs_diff)
+            }
 
-    pub const J: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+            pub fn relative_eq(self, other: $Vec4, max_abs_diff: $t, max_relative: $t) -> bool {
+                math::$t::relative_eq(&self.to_array(), &other.to_array(), max_abs_diff, max_relative)
+            }
 
-    pub const K: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+            pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
 
-    pub const L: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+            pub const NAN: Self = Self::new($t::NAN, $t::NAN, $t::NAN, $t::NAN);
 
-    pub const I_NEG: Self = Self::new(-1.0, 0.0, 0.0, 0.0);
+            pub const ONE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
 
-    pub const J_NEG: Self = Self::new(0.0, -1.0, 0.0, 0.0);
+            pub const ONE_NEG: Self = Self::new(-1.0, -1.0, -1.0, -1.0);
 
-    pub const K_NEG: Self = Self::new(0.0, 0.0, -1.0, 0.0);
+            pub const I: Self = Self::new(1.0, 0.0, 0.0, 0.0);
 
-    pub const L_NEG: Self = Self::new(0.0, 0.0, 0.0, -1.0);
+            pub const J: Self = Self::new(0.0, 1.0, 0.0, 0.0);
 
-    pub fn norm(self) -> f32 {
-        f32::sqrt(f32::powi(self.i, 2) 
-                + f32::powi(self.j, 2) 
-                + f32::powi(self.k, 2) 
-                + f32::powi(self.l, 2))
-    }
+            pub const K: Self = Self::new(0.0, 0.0, 1.0, 0.0);
 
-    pub fn norm_squared(self) -> f32 {
-        f32::powi(self.i, 2) 
-      + f32::powi(self.j, 2) 
-      + f32::powi(self.k, 2) 
-      + f32::powi(self.l, 2)
-    }
+            pub const L: Self = Self::new(0.0, 0.0, 0.0, 1.0);
 
-    pub fn abs(self) -> Self {
-        Self {
-            i: f32::abs(self.i),
-            j: f32::abs(self.j),
-            k: f32::abs(self.k),
-            l: f32::abs(self.l),
-        }
-    }
+            pub const I_NEG: Self = Self::new(-1.0, 0.0, 0.0, 0.0);
 
-    pub fn dist(self, other: Vec4) -> f32 {
-        (self - other).norm()
-    }
+            pub const J_NEG: Self = Self::new(0.0, -1.0, 0.0, 0.0);
 
-    pub fn dist_squared(self, other: Vec4) -> f32{
-        (self - other).norm_squared()
-    }
+            pub const K_NEG: Self = Self::new(0.0, 0.0, -1.0, 0.0);
+
+            pub const L_NEG: Self = Self::new(0.0, 0.0, 0.0, -1.0);
+
+            pub fn norm(self) -> $t {
+                math::$t::sqrt(math::$t::powi(self.i, 2) 
+                        + math::$t::powi(self.j, 2) 
+                        + math::$t::powi(self.k, 2) 
+                        + math::$t::powi(self.l, 2))
+            }
+
+            pub fn norm_squared(self) -> $t {
+                math::$t::powi(self.i, 2) 
+              + math::$t::powi(self.j, 2) 
+              + math::$t::powi(self.k, 2) 
+              + math::$t::powi(self.l, 2)
+            }
+
+            pub fn abs(self) -> Self {
+                Self {
+                    i: $t::abs(self.i),
+                    j: $t::abs(self.j),
+                    k: $t::abs(self.k),
+                    l: $t::abs(self.l),
+                }
+            }
+
+            pub fn dist(self, other: $Vec4) -> $t {
+                (self - other).norm()
+            }
+
+            pub fn dist_squared(self, other: $Vec4) -> $t{
+                (self - other).norm_squared()
+            }
+
+            // be careful, divide by zero error!
+            pub fn normalize(self) -> Self {
+                self / self.norm()
+            }
+
+            // None if the norm is zero, infinite or NaN
+            pub fn try_normalize(self) -> Option<Self> {
+                let norm_inv: $t = 1.0 / self.norm();
+                if norm_inv.is_finite() && norm_inv > 0.0 {
+                    Some(self * norm_inv)
+                } else {
+                    None
+                }
+            }
+
+            pub fn normalize_or(self, fallback: $Vec4) -> Self {
+                self.try_normalize().unwrap_or(fallback)
+            }
+
+            pub fn normalize_or_zero(self) -> Self {
+                self.normalize_or(Self::ZERO)
+            }
+
+            // Checks the squared norm against 1
+            pub fn is_normalized(self, epsilon: $t) -> bool {
+                (self.norm_squared() - 1.0).abs() <= epsilon
+            }
+
+            pub fn normalize_or_nan(self) -> Self {
+                self.normalize_or(Self::NAN)
+            }
+
+            pub fn midpoint(self, other: $Vec4) -> $Vec4 {
+                (self + other) * 0.5
+            }
+
+            // t = 0 gives self and t = 1 gives other
+            pub fn lerp(self, other: $Vec4, t: $t) -> Self {
+                self + (other - self) * t
+            }
+
+            // Mirrors self about the plane with the given normal, which
+            // should be normalized
+            pub fn reflect(self, normal: $Vec4) -> Self {
+                self - normal * (2.0 * (self * normal))
+            }
+
+            // Bends self through a surface with the given normal, eta being
+            // the ratio of the refractive indices. Both self and normal should
+            // be normalized. Returns ZERO on total internal reflection.
+            pub fn refract(self, normal: $Vec4, eta: $t) -> Self {
+                let cos: $t = self * normal;
+                let k: $t = 1.0 - eta * eta * (1.0 - cos * cos);
+                if k < 0.0 {
+                    Self::ZERO
+                } else {
+                    self * eta - normal * (eta * cos + math::$t::sqrt(k))
+                }
+            }
+
+            // be careful, divide by zero error!
+            pub fn project_onto(self, other: $Vec4) -> Self {
+                other * ((self * other) / other.norm_squared())
+            }
+
+            // The part of self perpendicular to other
+            // be careful, divide by zero error!
+            pub fn reject_from(self, other: $Vec4) -> Self {
+                self - self.project_onto(other)
+            }
+
+            // In radians, between 0 and pi
+            pub fn angle_between(self, other: $Vec4) -> $t {
+                let cos: $t = (self * other) / math::$t::sqrt(self.norm_squared() * other.norm_squared());
+                math::$t::acos(cos.clamp(-1.0, 1.0))
+            }
+
+            // A zero vector has no direction to stretch along, so it is returned
+            // as is even when min is above zero
+            pub fn clamp_length(self, min: $t, max: $t) -> Self {
+                let norm: $t = self.norm();
+                if norm == 0.0 {
+                    self
+                } else if norm < min {
+                    self * (min / norm)
+                } else if norm > max {
+                    self * (max / norm)
+                } else {
+                    self
+                }
+            }
+
+            pub fn min(self, other: $Vec4) -> Self {
+                Self {
+                    i: self.i.min(other.i),
+                    j: self.j.min(other.j),
+                    k: self.k.min(other.k),
+                    l: self.l.min(other.l),
+                }
+            }
 
-    // be careful, divide by zero error!
-    pub fn normalize(self) -> Self {
-        Self {
-            i: self.i / self.norm(),
-            j: self.j / self.norm(),
-            k: self.k / self.norm(),
-            l: self.l / self.norm(),
+            pub fn max(self, other: $Vec4) -> Self {
+                Self {
+                    i: self.i.max(other.i),
+                    j: self.j.max(other.j),
+                    k: self.k.max(other.k),
+                    l: self.l.max(other.l),
+                }
+            }
+
+            // Component-wise, min should not be greater than max
+            pub fn clamp(self, min: $Vec4, max: $Vec4) -> Self {
+                self.max(min).min(max)
+            }
+
+            pub fn floor(self) -> Self {
+                Self {
+                    i: math::$t::floor(self.i),
+                    j: math::$t::floor(self.j),
+                    k: math::$t::floor(self.k),
+                    l: math::$t::floor(self.l),
+                }
+            }
+
+            pub fn ceil(self) -> Self {
+                Self {
+                    i: math::$t::ceil(self.i),
+                    j: math::$t::ceil(self.j),
+                    k: math::$t::ceil(self.k),
+                    l: math::$t::ceil(self.l),
+                }
+            }
+
+            pub fn round(self) -> Self {
+                Self {
+                    i: math::$t::round(self.i),
+                    j: math::$t::round(self.j),
+                    k: math::$t::round(self.k),
+                    l: math::$t::round(self.l),
+                }
+            }
+
+            pub fn signum(self) -> Self {
+                Self {
+                    i: self.i.signum(),
+                    j: self.j.signum(),
+                    k: self.k.signum(),
+                    l: self.l.signum(),
+                }
+            }
+
+            pub fn min_element(self) -> $t {
+                self.i.min(self.j).min(self.k).min(self.l)
+            }
+
+            pub fn max_element(self) -> $t {
+                self.i.max(self.j).max(self.k).max(self.l)
+            }
+
+            pub fn is_finite(self) -> bool {
+                self.i.is_finite() && self.j.is_finite() && self.k.is_finite() && self.l.is_finite()
+            }
+
+            pub fn is_nan(self) -> bool {
+                self.i.is_nan() || self.j.is_nan() || self.k.is_nan() || self.l.is_nan()
+            }
+
+            // Component-wise product
+            pub fn mul_elem(self, other: $Vec4) -> Self {
+                Self {
+                    i: self.i * other.i,
+                    j: self.j * other.j,
+                    k: self.k * other.k,
+                    l: self.l * other.l,
+                }
+            }
+
+            // Component-wise quotient
+            pub fn div_elem(self, other: $Vec4) -> Self {
+                Self {
+                    i: self.i / other.i,
+                    j: self.j / other.j,
+                    k: self.k / other.k,
+                    l: self.l / other.l,
+                }
+            }
         }
-    }
 
-    pub fn normalize_or_nan(self) -> Self {
-        if self.norm() > 0.0 {
-            Self {
-                i: self.i / self.norm(),
-                j: self.j / self.norm(),
-                k: self.k / self.norm(),
-                l: self.l / self.norm(),
-            }
-        } else {
-            Self{
-                i: f32::NAN,
-                j: f32::NAN,
-                k: f32::NAN,
-                l: f32::NAN,
+        // Operation Traits
+        impl Add<$Vec4> for $Vec4 {
+            type Output = Self;
+            fn add(self, val: Self) -> Self{
+                Self {
+                    i: self.i.add(val.i),
+                    j: self.j.add(val.j),
+                    k: self.k.add(val.k),
+                    l: self.l.add(val.l),
+                }
             }
         }
-    }
 
-    pub fn midpoint(self, other: Vec4) -> Vec4 {
-        (self + other) * 0.5
-    }
-}
+        impl Add<$t> for $Vec4 {
+            type Output = Self;
+            fn add(self, val: $t) -> Self{
+                Self {
+                    i: self.i.add(val),
+                    j: self.j.add(val),
+                    k: self.k.add(val),
+                    l: self.l.add(val),
+                }
+            }
+        }
 
-// Operation Traits
-impl Add<Vec4> for Vec4 {
-    type Output = Self;
-    fn add(self, val: Self) -> Self{
-        Self {
-            i: self.i.add(val.i),
-            j: self.j.add(val.j),
-            k: self.k.add(val.k),
-            l: self.l.add(val.l),
+        impl Sub<$Vec4> for $Vec4 {
+            type Output = Self;
+            fn sub(self, val: Self) -> Self{
+                Self {
+                    i: self.i.sub(val.i),
+                    j: self.j.sub(val.j),
+                    k: self.k.sub(val.k),
+                    l: self.l.sub(val.l),
+                }
+            }
         }
-    }
-}
 
-impl Add<f32> for Vec4 {
-    type Output = Self;
-    fn add(self, val: f32) -> Self{
-        Self {
-            i: self.i.add(val),
-            j: self.j.add(val),
-            k: self.k.add(val),
-            l: self.l.add(val),
+        impl Sub<$t> for $Vec4 {
+            type Output = Self;
+            fn sub(self, val: $t) -> Self{
+                Self {
+                    i: self.i.sub(val),
+                    j: self.j.sub(val),
+                    k: self.k.sub(val),
+                    l: self.l.sub(val),
+                }
+            }
         }
-    }
-}
 
-impl Sub<Vec4> for Vec4 {
-    type Output = Self;
-    fn sub(self, val: Self) -> Self{
-        Self {
-            i: self.i.sub(val.i),
-            j: self.j.sub(val.j),
-            k: self.k.sub(val.k),
-            l: self.l.sub(val.l),
+        // Vector dot product
+        impl Mul<$Vec4> for $Vec4 {
+            type Output = $t;
+            fn mul(self, val: Self) -> $t {
+                self.i * val.i + self.j * val.j + self.k * val.k + self.l * val.l 
+            }
         }
-    }
-}
 
-impl Sub<f32> for Vec4 {
-    type Output = Self;
-    fn sub(self, val: f32) -> Self{
-        Self {
-            i: self.i.sub(val),
-            j: self.j.sub(val),
-            k: self.k.sub(val),
-            l: self.l.sub(val),
+        impl Mul<$t> for $Vec4 {
+            type Output = Self;
+            fn mul(self, val: $t) -> Self {
+                Self {
+                    i: self.i.mul(val),
+                    j: self.j.mul(val),
+                    k: self.k.mul(val),
+                    l: self.l.mul(val),
+                }
+            }
         }
-    }
-}
 
-// Vector dot product
-impl Mul<Vec4> for Vec4 {
-    type Output = f32;
-    fn mul(self, val: Self) -> f32 {
-        self.i * val.i + self.j * val.j + self.k * val.k + self.l * val.l 
-    }
-}
+        impl Div<$t> for $Vec4 {
+            type Output = Self;
+            fn div(self, val: $t) -> $Vec4 {
+                Self {
+                    i: self.i.div(val),
+                    j: self.j.div(val),
+                    k: self.k.div(val),
+                    l: self.l.div(val),
+                }
+            }
+        }
 
-impl Mul<f32> for Vec4 {
-    type Output = Self;
-    fn mul(self, val: f32) -> Self {
-        Self {
-            i: self.i.mul(val),
-            j: self.j.mul(val),
-            k: self.k.mul(val),
-            l: self.l.mul(val),
+        impl Neg for $Vec4 {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self {
+                    i: -self.i,
+                    j: -self.j,
+                    k: -self.k,
+                    l: -self.l,
+                }
+            }
         }
-    }
-}
 
-impl Div<f32> for Vec4 {
-    type Output = Self;
-    fn div(self, val: f32) -> Vec4 {
-        Self {
-            i: self.i.div(val),
-            j: self.j.div(val),
-            k: self.k.div(val),
-            l: self.l.div(val),
+        impl Index<usize> for $Vec4 {
+            type Output = $t;
+            fn index(&self, index: usize) -> &$t {
+                match index {
+                    0 => &self.i,
+                    1 => &self.j,
+                    2 => &self.k,
+                    3 => &self.l,
+                    _ => panic!("index out of bounds: the len is 4 but the index is {}", index),
+                }
+            }
         }
-    }
+
+        impl IndexMut<usize> for $Vec4 {
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                match index {
+                    0 => &mut self.i,
+                    1 => &mut self.j,
+                    2 => &mut self.k,
+                    3 => &mut self.l,
+                    _ => panic!("index out of bounds: the len is 4 but the index is {}", index),
+                }
+            }
+        }
+
+        impl_ref_ops!($Vec4;
+            Add<$Vec4>, add;
+            Add<$t>, add;
+            Sub<$Vec4>, sub;
+            Sub<$t>, sub;
+            Mul<$Vec4>, mul;
+            Mul<$t>, mul;
+            Div<$t>, div;
+        );
+
+        impl_assign_ops!($Vec4;
+            AddAssign<$Vec4>, add_assign, Add, add;
+            AddAssign<$t>, add_assign, Add, add;
+            SubAssign<$Vec4>, sub_assign, Sub, sub;
+            SubAssign<$t>, sub_assign, Sub, sub;
+            MulAssign<$t>, mul_assign, Mul, mul;
+            DivAssign<$t>, div_assign, Div, div;
+        );
+
+        impl_scalar_lhs_mul!($Vec4, $t);
+        impl_ref_neg!($Vec4);
+    };
 }
 
+// With simd the columns of a Mat4 can be loaded straight into registers
+impl_vec4!(#[cfg_attr(feature = "simd", repr(align(16)))] Vec4, f32, vec4);
+impl_vec4!(DVec4, f64, dvec4);
 
+impl Vec4 {
+    pub fn as_f64(self) -> DVec4 {
+        DVec4::new(self.i as f64, self.j as f64, self.k as f64, self.l as f64)
+    }
+}
 
+impl DVec4 {
+    pub fn as_f32(self) -> Vec4 {
+        Vec4::new(self.i as f32, self.j as f32, self.k as f32, self.l as f32)
+    }
+}
@@ -0,0 +1,82 @@
+use rgla::{Affine3, Bivec3, Mat4, Rot, Vec3, Vec4};
+
+fn uniform() -> Affine3 {
+    Affine3::new(
+        Vec3::new(2.0, 2.0, 2.0),
+        Rot::from_angle_plane(0.8, Bivec3::new(0.3, -1.0, 0.5)),
+        Vec3::new(1.0, -2.0, 3.0),
+    )
+}
+
+fn point_through(mat: Mat4, point: Vec3) -> Vec3 {
+    let p: Vec4 = mat * Vec4::new(point.i, point.j, point.k, 1.0);
+    Vec3::new(p.i, p.j, p.k)
+}
+
+#[test]
+fn transform_point_matches_to_mat4() {
+    let a: Affine3 = Affine3::new(Vec3::new(2.0, 0.5, 3.0), uniform().rot, uniform().translation);
+    let p: Vec3 = Vec3::new(0.4, 1.0, -0.7);
+    assert!(a.transform_point(p).abs_diff_eq(point_through(a.to_mat4(), p), 1e-5));
+    assert!(Affine3::from_mat4(a.to_mat4()).abs_diff_eq(a, 1e-5));
+}
+
+#[test]
+fn compose_applies_other_first() {
+    let a: Affine3 = uniform();
+    let b: Affine3 = Affine3::new(
+        Vec3::new(1.0, 3.0, 0.5),
+        Rot::from_angle_plane(-0.4, Bivec3::B02),
+        Vec3::new(0.0, 1.0, 1.0),
+    );
+    let p: Vec3 = Vec3::new(0.4, 1.0, -0.7);
+    assert!((a * b).transform_point(p).abs_diff_eq(a.transform_point(b.transform_point(p)), 1e-5));
+    assert!((a * b).to_mat4().abs_diff_eq(a.to_mat4() * b.to_mat4(), 1e-5));
+}
+
+#[test]
+fn inverse_undoes_the_transform() {
+    let a: Affine3 = uniform();
+    let p: Vec3 = Vec3::new(0.4, 1.0, -0.7);
+    assert!(a.inverse().transform_point(a.transform_point(p)).abs_diff_eq(p, 1e-5));
+    assert!((a * a.inverse()).abs_diff_eq(Affine3::IDENTITY, 1e-5));
+    assert!(a.inverse().to_mat4().abs_diff_eq(a.to_mat4().inverse(), 1e-5));
+}
+
+#[test]
+fn try_inverse_rejects_what_inverse_cant_represent() {
+    let scaled: Affine3 = Affine3::new(Vec3::new(2.0, 0.5, 3.0), Rot::IDENTITY, Vec3::new(1.0, 0.0, 0.0));
+    let p: Vec3 = Vec3::new(0.4, 1.0, -0.7);
+    let inv: Affine3 = scaled.try_inverse().unwrap();
+    assert!(inv.transform_point(scaled.transform_point(p)).abs_diff_eq(p, 1e-6));
+
+    assert!(uniform().try_inverse().is_some());
+    assert_eq!(Affine3::new(Vec3::new(2.0, 0.5, 3.0), uniform().rot, Vec3::ZERO).try_inverse(), None);
+    assert_eq!(Affine3::from_scale(Vec3::new(1.0, 0.0, 1.0)).try_inverse(), None);
+}
+
+#[test]
+fn compose_drops_the_shear_of_a_non_uniform_parent() {
+    // A scene graph parent with a non-uniform scale and a rotated child
+    let parent: Affine3 = Affine3::new(Vec3::new(1.0, 2.0, 3.0), Rot::IDENTITY, Vec3::new(0.0, 1.0, 0.0));
+    let child: Affine3 = Affine3::new(Vec3::ONE, uniform().rot, Vec3::new(1.0, 1.0, 1.0));
+    let lossy: Affine3 = parent * child;
+    assert_eq!(lossy, parent.compose(child));
+    assert_eq!(parent.try_compose(child), None);
+
+    // The child's origin is still exact, other points are not
+    assert!(lossy.transform_point(Vec3::ZERO).abs_diff_eq(parent.transform_point(child.translation), 1e-6));
+    let p: Vec3 = Vec3::new(0.4, 1.0, -0.7);
+    assert!(!lossy.transform_point(p).abs_diff_eq(parent.transform_point(child.transform_point(p)), 1e-3));
+}
+
+#[test]
+fn try_compose_accepts_what_compose_represents() {
+    let scaled: Affine3 = Affine3::from_scale(Vec3::new(1.0, 2.0, 3.0));
+    let p: Vec3 = Vec3::new(0.4, 1.0, -0.7);
+    assert_eq!(uniform().try_compose(scaled), Some(uniform() * scaled));
+    assert_eq!(scaled.try_compose(Affine3::from_translation(Vec3::I)), Some(scaled * Affine3::from_translation(Vec3::I)));
+    let b: Affine3 = Affine3::new(Vec3::new(2.0, 0.5, 3.0), uniform().rot, Vec3::J);
+    let exact: Affine3 = uniform().try_compose(b).unwrap();
+    assert!(exact.transform_point(p).abs_diff_eq(uniform().transform_point(b.transform_point(p)), 1e-5));
+}
@@ -0,0 +1,59 @@
+use rgla::{DMat4, DVec3, Mat3, Rot, Vec3};
+
+fn arbitrary_rot() -> Rot {
+    Rot::new(0.5, -0.3, 0.8, 0.1).normalize()
+}
+
+#[test]
+fn rot_and_its_negation_are_equal() {
+    let r: Rot = arbitrary_rot();
+    assert_ne!(r, -r);
+    assert!(r.abs_diff_eq(-r, 0.0));
+    assert!(r.relative_eq(-r, 0.0, 0.0));
+    assert!(r.abs_diff_eq(-r * 1.000001, 1e-5));
+    assert!(!r.abs_diff_eq(Rot::IDENTITY, 1e-5));
+    assert!(!r.abs_diff_eq(r.reverse(), 1e-5));
+}
+
+#[test]
+fn vector_tolerances() {
+    let v: Vec3 = Vec3::new(1.0, 1000.0, -0.001);
+    let w: Vec3 = Vec3::new(1.0, 1000.01, -0.001);
+    assert!(!v.abs_diff_eq(w, 1e-3));
+    assert!(v.abs_diff_eq(w, 0.02));
+    // 0.01 is a relative difference of 1e-5 on 1000
+    assert!(v.relative_eq(w, 1e-6, 2e-5));
+    assert!(!v.relative_eq(w, 1e-6, 5e-6));
+    assert!(!v.abs_diff_eq(Vec3::new(1.0, 1000.0, f32::NAN), 1.0));
+}
+
+#[test]
+fn matrix_tolerances() {
+    let m: Mat3 = arbitrary_rot().to_mat3();
+    let nudged: Mat3 = m + 1e-6;
+    assert_ne!(m, nudged);
+    assert!(m.abs_diff_eq(nudged, 1e-5));
+    assert!(!m.abs_diff_eq(nudged, 1e-7));
+    assert!(m.relative_eq(m * 1.000001, 0.0, 1e-5));
+    assert!(!m.relative_eq(m * 1.001, 0.0, 1e-5));
+
+    let d: DMat4 = DMat4::look_at_right(DVec3::new(1.0, 2.0, 3.0), DVec3::ZERO, DVec3::J);
+    assert!((d * d.inverse()).relative_eq(DMat4::I, 1e-12, 1e-12));
+}
+
+#[cfg(feature = "approx")]
+mod traits {
+    use super::*;
+    use rgla::Bivec3;
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne};
+
+    #[test]
+    fn macros_use_the_inherent_comparisons() {
+        let r: Rot = arbitrary_rot();
+        assert_abs_diff_eq!(r, -r);
+        assert_relative_eq!(r, -r);
+        assert_relative_eq!(Rot::from_angle_plane(1.0, Bivec3::B01).rotate(Vec3::I), Vec3::new(0.5403023, 0.84147096, 0.0), epsilon = 1e-6);
+        assert_relative_eq!(r.to_mat3() * r.to_mat3().transpose(), Mat3::I, epsilon = 1e-6);
+        assert_relative_ne!(r.to_mat3(), Mat3::I, epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,87 @@
+use rgla::{DRot, EulerOrder, Mat3, Mat4, Rot};
+use std::f32::consts::FRAC_PI_2;
+
+const ORDERS: [EulerOrder; 6] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+];
+
+fn single_axis(order: EulerOrder, slot: usize, angle: f32) -> Mat4 {
+    let mut angles: [f32; 3] = [0.0; 3];
+    angles[slot] = angle;
+    Mat4::from_euler(order, angles[0], angles[1], angles[2])
+}
+
+// The rotation each slot of the order turns about
+fn axis_rotation(order: EulerOrder, slot: usize, angle: f32) -> Mat4 {
+    let x: fn(f32) -> Mat4 = Mat4::from_rotation_i;
+    let y: fn(f32) -> Mat4 = Mat4::from_rotation_j;
+    let z: fn(f32) -> Mat4 = Mat4::from_rotation_k;
+    let axes: [fn(f32) -> Mat4; 3] = match order {
+        EulerOrder::XYZ => [x, y, z],
+        EulerOrder::XZY => [x, z, y],
+        EulerOrder::YXZ => [y, x, z],
+        EulerOrder::YZX => [y, z, x],
+        EulerOrder::ZXY => [z, x, y],
+        EulerOrder::ZYX => [z, y, x],
+    };
+    axes[slot](angle)
+}
+
+#[test]
+fn single_axis_matches_from_rotation() {
+    for order in ORDERS {
+        for slot in 0..3 {
+            let expected: Mat4 = axis_rotation(order, slot, 0.6);
+            assert!(single_axis(order, slot, 0.6).abs_diff_eq(expected, 1e-6), "{:?} {}", order, slot);
+        }
+    }
+    assert!(Mat4::from_euler(EulerOrder::XYZ, 0.0, 0.6, 0.0).abs_diff_eq(Mat4::from_rotation_j(0.6), 1e-6));
+}
+
+#[test]
+fn first_angle_is_applied_first() {
+    let (a, b, c) = (0.3, -0.8, 1.1);
+    for order in ORDERS {
+        let expected: Mat4 = axis_rotation(order, 2, c) * axis_rotation(order, 1, b) * axis_rotation(order, 0, a);
+        assert!(Mat4::from_euler(order, a, b, c).abs_diff_eq(expected, 1e-6), "{:?}", order);
+    }
+}
+
+#[test]
+fn rot_and_matrices_agree() {
+    let (a, b, c) = (0.3, -0.8, 1.1);
+    for order in ORDERS {
+        let rot: Rot = Rot::from_euler(order, a, b, c);
+        assert!(rot.to_mat3().abs_diff_eq(Mat3::from_euler(order, a, b, c), 1e-6), "{:?}", order);
+        assert!(rot.to_mat4().abs_diff_eq(Mat4::from_euler(order, a, b, c), 1e-6), "{:?}", order);
+    }
+}
+
+#[test]
+fn to_euler_round_trips() {
+    let angles: [(f64, f64, f64); 4] = [(0.3, -0.8, 1.1), (-2.9, 1.2, 0.4), (2.0, -1.5, -3.0), (0.0, 0.0, 0.0)];
+    for order in ORDERS {
+        for (a, b, c) in angles {
+            let (a2, b2, c2) = DRot::from_euler(order, a, b, c).to_euler(order);
+            assert!((a2 - a).abs() < 1e-12 && (b2 - b).abs() < 1e-12 && (c2 - c).abs() < 1e-12, "{:?} {:?}", order, (a2, b2, c2));
+        }
+    }
+}
+
+#[test]
+fn gimbal_lock_keeps_the_rotation() {
+    for order in ORDERS {
+        for b in [FRAC_PI_2, -FRAC_PI_2] {
+            let rot: Rot = Rot::from_euler(order, 0.4, b, -0.9);
+            let (a2, b2, c2) = rot.to_euler(order);
+            assert_eq!(c2, 0.0);
+            assert!((b2 - b).abs() < 1e-3);
+            assert!(Rot::from_euler(order, a2, b2, c2).abs_diff_eq(rot, 1e-5), "{:?} {}", order, b);
+        }
+    }
+}
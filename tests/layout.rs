@@ -0,0 +1,70 @@
+use rgla::*;
+use std::mem::size_of;
+
+#[test]
+fn types_are_packed_floats() {
+    assert_eq!(size_of::<Vec2>(), 8);
+    assert_eq!(size_of::<Vec3>(), 12);
+    assert_eq!(size_of::<Vec4>(), 16);
+    assert_eq!(size_of::<Mat3>(), 36);
+    assert_eq!(size_of::<Mat4>(), 64);
+    assert_eq!(size_of::<Rot>(), 16);
+    assert_eq!(size_of::<DMat4>(), 128);
+    assert_eq!(size_of::<Motor>(), 32);
+}
+
+#[test]
+fn as_array_views_the_components_in_order() {
+    let v: Vec3 = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.as_array(), &[1.0, 2.0, 3.0]);
+    assert_eq!(v.as_array().as_ptr(), &v.i as *const f32);
+    assert_eq!(Vec3::from_array(v.to_array()), v);
+
+    let r: Rot = Rot::new(0.5, -0.3, 0.8, 0.1);
+    assert_eq!(r.as_array(), &[0.5, -0.3, 0.8, 0.1]);
+    assert_eq!(Rot::from_array(r.to_array()), r);
+    assert_eq!(Line::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0).as_array(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+}
+
+#[test]
+fn cols_arrays_are_column_major() {
+    let m: Mat3 = Mat3::from_rows(
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(4.0, 5.0, 6.0),
+        Vec3::new(7.0, 8.0, 9.0),
+    );
+    assert_eq!(m.to_cols_array(), [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]);
+    assert_eq!(Mat3::from_cols_array(&m.to_cols_array()), m);
+
+    let t: Mat4 = Mat4::from_translation(Vec3::new(5.0, 6.0, 7.0));
+    assert_eq!(&t.to_cols_array()[12..], &[5.0, 6.0, 7.0, 1.0]);
+    assert_eq!(Mat4::from_cols_array(&t.to_cols_array()), t);
+    assert_eq!(Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]).col2, Vec2::new(3.0, 4.0));
+}
+
+#[cfg(feature = "bytemuck")]
+mod pod {
+    use super::*;
+
+    #[test]
+    fn cast_matches_the_column_major_array() {
+        let m: Mat4 = Mat4::from_translation(Vec3::new(5.0, 6.0, 7.0)) * Mat4::from_rotation_k(0.3);
+        let array: [f32; 16] = bytemuck::cast(m);
+        assert_eq!(array, m.to_cols_array());
+        assert_eq!(bytemuck::cast::<[f32; 16], Mat4>(array), m);
+        assert_eq!(bytemuck::cast_ref::<Mat4, [f32; 16]>(&m), &m.to_cols_array());
+
+        let d: DMat3 = DMat3::from_rotation(0.3);
+        assert_eq!(bytemuck::cast::<DMat3, [f64; 9]>(d), d.to_cols_array());
+    }
+
+    #[test]
+    fn cast_slice_of_vectors() {
+        let points: [Vec3; 2] = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)];
+        let floats: &[f32] = bytemuck::cast_slice(&points);
+        assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(bytemuck::cast_slice::<f32, Vec3>(floats), &points);
+        assert_eq!(bytemuck::cast::<Rot, [f32; 4]>(Rot::IDENTITY), [1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(<Vec4 as bytemuck::Zeroable>::zeroed(), Vec4::ZERO);
+    }
+}
@@ -0,0 +1,22 @@
+use rgla::{Mat2, Vec2};
+
+fn sample() -> Mat2 {
+    Mat2::from_rows(Vec2::new(3.0, 1.0), Vec2::new(4.0, 2.0))
+}
+
+#[test]
+fn determinant_and_inverse() {
+    let m: Mat2 = sample();
+    assert_eq!(m.determinant(), 2.0);
+    assert_eq!(m.inverse(), Mat2::from_rows(Vec2::new(1.0, -0.5), Vec2::new(-2.0, 1.5)));
+    assert!((m * m.inverse()).abs_diff_eq(Mat2::I, 1e-6));
+    assert_eq!(m.transpose().determinant(), m.determinant());
+}
+
+#[test]
+fn singular_has_no_inverse() {
+    let m: Mat2 = Mat2::from_rows(Vec2::new(1.0, 2.0), Vec2::new(2.0, 4.0));
+    assert_eq!(m.determinant(), 0.0);
+    assert_eq!(m.try_inverse(), None);
+    assert_eq!(sample().try_inverse(), Some(sample().inverse()));
+}
@@ -0,0 +1,47 @@
+use rgla::{Mat3, Rot, Vec3};
+
+fn sample() -> Mat3 {
+    Mat3::from_rows(
+        Vec3::new(2.0, 0.0, 1.0),
+        Vec3::new(1.0, 3.0, 2.0),
+        Vec3::new(1.0, 1.0, 2.0),
+    )
+}
+
+#[test]
+fn determinant() {
+    assert_eq!(Mat3::I.determinant(), 1.0);
+    assert_eq!(sample().determinant(), 6.0);
+    assert_eq!(sample().transpose().determinant(), 6.0);
+    // Swapping two columns flips the sign
+    let m: Mat3 = sample();
+    assert_eq!(Mat3::from_cols(m.col2, m.col1, m.col3).determinant(), -6.0);
+}
+
+#[test]
+fn inverse_undoes_the_matrix() {
+    let m: Mat3 = sample();
+    assert!((m * m.inverse()).abs_diff_eq(Mat3::I, 1e-6));
+    assert!((m.inverse() * m).abs_diff_eq(Mat3::I, 1e-6));
+    let v: Vec3 = Vec3::new(0.5, -1.0, 2.0);
+    assert!((m.inverse() * (m * v)).abs_diff_eq(v, 1e-6));
+}
+
+#[test]
+fn inverse_of_a_rotation_is_its_transpose() {
+    let m: Mat3 = Rot::new(0.5, -0.3, 0.8, 0.1).normalize().to_mat3();
+    assert!((m.determinant() - 1.0).abs() < 1e-6);
+    assert!(m.inverse().abs_diff_eq(m.transpose(), 1e-6));
+}
+
+#[test]
+fn singular_has_no_inverse() {
+    let m: Mat3 = Mat3::from_rows(
+        Vec3::new(2.0, 0.0, 1.0),
+        Vec3::new(1.0, 3.0, 2.0),
+        Vec3::new(1.0, 1.0, 1.0),
+    );
+    assert_eq!(m.determinant(), 0.0);
+    assert_eq!(m.try_inverse(), None);
+    assert_eq!(Mat3::ZERO.try_inverse(), None);
+}
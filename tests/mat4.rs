@@ -0,0 +1,122 @@
+use rgla::{Mat4, Rot, Vec3, Vec4};
+
+fn sample() -> Mat4 {
+    Mat4::from_rows(
+        Vec4::new(1.0, 0.0, 2.0, 0.0),
+        Vec4::new(0.0, 3.0, 0.0, 1.0),
+        Vec4::new(4.0, 0.0, 5.0, 0.0),
+        Vec4::new(0.0, 1.0, 0.0, 2.0),
+    )
+}
+
+fn affine() -> Mat4 {
+    Mat4::from_translation(Vec3::new(1.0, -2.0, 3.0))
+        * Rot::new(0.5, -0.3, 0.8, 0.1).normalize().to_mat4()
+        * Mat4::from_scale(Vec3::new(2.0, 0.5, 3.0))
+}
+
+#[test]
+fn determinant() {
+    assert_eq!(Mat4::I.determinant(), 1.0);
+    assert_eq!(sample().determinant(), -15.0);
+    assert_eq!(sample().transpose().determinant(), -15.0);
+    assert!((affine().determinant() - 3.0).abs() < 1e-5);
+}
+
+#[test]
+fn inverse_undoes_the_matrix() {
+    let m: Mat4 = sample();
+    assert!((m * m.inverse()).abs_diff_eq(Mat4::I, 1e-6));
+    assert!((m.inverse() * m).abs_diff_eq(Mat4::I, 1e-6));
+    let v: Vec4 = Vec4::new(0.5, -1.0, 2.0, 1.0);
+    assert!((m.inverse() * (m * v)).abs_diff_eq(v, 1e-5));
+}
+
+#[test]
+fn inverse_affine_matches_inverse() {
+    let m: Mat4 = affine();
+    assert!(m.inverse_affine().abs_diff_eq(m.inverse(), 1e-5));
+    assert!((m * m.inverse_affine()).abs_diff_eq(Mat4::I, 1e-5));
+}
+
+#[test]
+fn singular_has_no_inverse() {
+    let m: Mat4 = Mat4::from_scale(Vec3::new(1.0, 0.0, 1.0));
+    assert_eq!(m.determinant(), 0.0);
+    assert_eq!(m.try_inverse(), None);
+    assert_eq!(m.try_inverse_affine(), None);
+}
+
+// Each one is counterclockwise looking down its axis towards the origin
+#[test]
+fn from_rotation_follows_the_right_hand_rule() {
+    let quarter: f32 = std::f32::consts::FRAC_PI_2;
+    assert!((Mat4::from_rotation_i(quarter) * Vec4::J).abs_diff_eq(Vec4::K, 1e-6));
+    assert!((Mat4::from_rotation_j(quarter) * Vec4::K).abs_diff_eq(Vec4::I, 1e-6));
+    assert!((Mat4::from_rotation_k(quarter) * Vec4::I).abs_diff_eq(Vec4::J, 1e-6));
+    assert!((Mat4::from_rotation_j(quarter) * Vec4::I).abs_diff_eq(-Vec4::K, 1e-6));
+}
+
+fn eye() -> Vec3 {
+    Vec3::new(1.0, 2.0, 3.0)
+}
+
+fn target() -> Vec3 {
+    Vec3::new(-2.0, 0.5, 1.0)
+}
+
+fn apply(mat: Mat4, point: Vec3) -> Vec4 {
+    mat * Vec4::new(point.i, point.j, point.k, 1.0)
+}
+
+#[test]
+fn look_at_puts_the_target_on_the_view_axis() {
+    let dist: f32 = (target() - eye()).norm();
+    let up: Vec3 = Vec3::J;
+
+    let left: Mat4 = Mat4::look_at_left(eye(), target(), up);
+    assert!(apply(left, target()).abs_diff_eq(Vec4::new(0.0, 0.0, dist, 1.0), 1e-5));
+    assert!(apply(left, eye()).abs_diff_eq(Vec4::new(0.0, 0.0, 0.0, 1.0), 1e-5));
+    assert!(apply(left, eye() + up).j > 0.0);
+
+    let right: Mat4 = Mat4::look_at_right(eye(), target(), up);
+    assert!(apply(right, target()).abs_diff_eq(Vec4::new(0.0, 0.0, -dist, 1.0), 1e-5));
+    assert!(apply(right, eye() + up).j > 0.0);
+
+    // The target ends up in front of the camera, in the middle of the screen
+    for (proj, view) in [
+        (Mat4::perspective_left(1.2, 1.5, 0.1, 100.0), left),
+        (Mat4::perspective_right(1.2, 1.5, 0.1, 100.0), right),
+    ] {
+        let clip: Vec4 = proj * apply(view, target());
+        assert!(clip.l > 0.0);
+        assert!(clip.i.abs() < 1e-5 && clip.j.abs() < 1e-5);
+    }
+}
+
+#[test]
+fn look_matrices_are_rigid() {
+    for view in [
+        Mat4::look_at_left(eye(), target(), Vec3::J),
+        Mat4::look_at_right(eye(), target(), Vec3::new(0.2, 1.0, 0.3)),
+        Mat4::look_to_left(eye(), Vec3::new(0.0, -1.0, 2.0), Vec3::K),
+        Mat4::look_to_right(eye(), Vec3::new(0.0, -1.0, 2.0), Vec3::K),
+    ] {
+        assert!((view.determinant() - 1.0).abs() < 1e-5);
+        let moved: f32 = (apply(view, target()) - apply(view, eye())).norm_squared();
+        assert!((moved - (target() - eye()).norm_squared()).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn look_rotation_is_the_inverse_camera() {
+    let dir: Vec3 = target() - eye();
+    let up: Vec3 = Vec3::new(0.2, 1.0, 0.3);
+    let camera: Mat4 = Mat4::from_translation(eye()) * Mat4::from_rot(Rot::look_rotation_left(dir, up));
+    assert!(camera.inverse().abs_diff_eq(Mat4::look_to_left(eye(), dir, up), 1e-5));
+    let camera: Mat4 = Mat4::from_translation(eye()) * Mat4::from_rot(Rot::look_rotation_right(dir, up));
+    assert!(camera.inverse().abs_diff_eq(Mat4::look_to_right(eye(), dir, up), 1e-5));
+
+    assert!(Rot::look_rotation_left(dir, up).rotate(Vec3::K).abs_diff_eq(dir.normalize(), 1e-6));
+    assert!(Rot::look_rotation_right(dir, up).rotate(-Vec3::K).abs_diff_eq(dir.normalize(), 1e-6));
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "mint")]
+
+use rgla::*;
+
+// v' = v + 2s (q x v) + 2 q x (q x v), the usual unit quaternion rotation
+fn rotate_by_quaternion(quat: mint::Quaternion<f32>, v: Vec3) -> Vec3 {
+    let q: Vec3 = quat.v.into();
+    let t: Vec3 = q.cross(v) * 2.0;
+    v + t * quat.s + q.cross(t)
+}
+
+#[test]
+fn rot_rotates_like_the_quaternion() {
+    let rots: [Rot; 4] = [
+        Rot::from_angle_plane(0.7, Bivec3::B01),
+        Rot::from_angle_plane(-1.2, Bivec3::B02),
+        Rot::from_angle_plane(2.5, Bivec3::B12),
+        Rot::new(0.5, -0.3, 0.8, 0.1).normalize(),
+    ];
+    let v: Vec3 = Vec3::new(0.3, -1.1, 0.8);
+    for r in rots {
+        let quat: mint::Quaternion<f32> = r.into();
+        assert!(rotate_by_quaternion(quat, v).abs_diff_eq(r.rotate(v), 1e-5), "{:?}", r);
+        assert_eq!(Rot::from(quat), r);
+    }
+}
+
+#[test]
+fn quarter_turn_about_z_matches_b01() {
+    // cos(pi/4) + sin(pi/4) k turns x towards y
+    let half: f32 = core::f32::consts::FRAC_1_SQRT_2;
+    let quat = mint::Quaternion { v: mint::Vector3 { x: 0.0, y: 0.0, z: half }, s: half };
+    let r: Rot = quat.into();
+    assert!(r.abs_diff_eq(Rot::from_angle_plane(core::f32::consts::FRAC_PI_2, Bivec3::B01), 1e-6));
+    assert!(r.rotate(Vec3::I).abs_diff_eq(Vec3::J, 1e-6));
+}
+
+#[test]
+fn vectors_round_trip() {
+    let v: Vec3 = Vec3::new(1.0, 2.0, 3.0);
+    let mint_v: mint::Vector3<f32> = v.into();
+    assert_eq!((mint_v.x, mint_v.y, mint_v.z), (1.0, 2.0, 3.0));
+    assert_eq!(Vec3::from(mint_v), v);
+
+    let v: Vec4 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    let mint_v: mint::Vector4<f32> = v.into();
+    assert_eq!(mint_v.w, 4.0);
+    assert_eq!(Vec4::from(mint_v), v);
+
+    let v: DVec2 = DVec2::new(-1.0, 0.5);
+    assert_eq!(DVec2::from(mint::Vector2::from(v)), v);
+}
+
+#[test]
+fn matrices_round_trip_by_columns() {
+    let m: Mat4 = Mat4::from_translation(Vec3::new(5.0, 6.0, 7.0)) * Mat4::from_rotation_k(0.3);
+    let mint_m: mint::ColumnMatrix4<f32> = m.into();
+    assert_eq!(Vec4::from(mint_m.w), Vec4::new(5.0, 6.0, 7.0, 1.0));
+    assert_eq!(Mat4::from(mint_m), m);
+
+    let m: Mat3 = Mat3::from_rot(Rot::new(0.5, -0.3, 0.8, 0.1).normalize());
+    let mint_m: mint::ColumnMatrix3<f32> = m.into();
+    assert_eq!(Vec3::from(mint_m.y), m.col2);
+    assert_eq!(Mat3::from(mint_m), m);
+
+    let m: DMat2 = DMat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(DMat2::from(mint::ColumnMatrix2::from(m)), m);
+}
@@ -0,0 +1,76 @@
+use rgla::{Bivec3, Multivec3, Rot, Vec3};
+
+fn vector(vec: Vec3) -> Multivec3 {
+    Multivec3::from(vec)
+}
+
+fn arbitrary() -> Multivec3 {
+    Multivec3::new(0.5, -1.0, 2.0, 0.25, 1.5, -0.75, 0.3, -2.0)
+}
+
+#[test]
+fn basis_products() {
+    let (i, j, k) = (vector(Vec3::I), vector(Vec3::J), vector(Vec3::K));
+    assert_eq!(i * i, Multivec3::ONE);
+    assert_eq!(i * j, Multivec3::from(Bivec3::B01));
+    assert_eq!(j * i, -Multivec3::from(Bivec3::B01));
+    assert_eq!(j * k, Multivec3::from(Bivec3::B12));
+    assert_eq!(i * j * k, Multivec3::T012);
+    assert_eq!(Multivec3::T012 * Multivec3::T012, -Multivec3::ONE);
+    assert_eq!(Multivec3::from(Bivec3::B02) * Multivec3::from(Bivec3::B02), -Multivec3::ONE);
+}
+
+#[test]
+fn product_of_vectors_is_dot_plus_wedge() {
+    let u: Vec3 = Vec3::new(1.0, 2.0, 3.0);
+    let v: Vec3 = Vec3::new(-4.0, 0.5, 2.0);
+    let uv: Multivec3 = vector(u) * vector(v);
+    assert_eq!(uv.scalar(), u * v);
+    assert_eq!(uv.bivector(), vector(u).wedge(vector(v)).bivector());
+    assert_eq!(uv.bivector().dual(), u.cross(v));
+    assert_eq!(uv.grade(1), Multivec3::ZERO);
+    assert_eq!(uv.grade(3), Multivec3::ZERO);
+}
+
+#[test]
+fn product_is_associative_and_reverses() {
+    let p: Multivec3 = arbitrary();
+    let q: Multivec3 = Multivec3::new(-0.2, 0.4, 1.0, -1.5, 0.6, 2.0, -0.1, 0.7);
+    let r: Multivec3 = Multivec3::new(1.0, 0.0, -0.5, 0.5, 0.25, 0.0, 1.0, 0.5);
+    assert!(((p * q) * r).abs_diff_eq(p * (q * r), 1e-5));
+    assert!((p * q).reverse().abs_diff_eq(q.reverse() * p.reverse(), 1e-5));
+    assert!((p * q).involute().abs_diff_eq(p.involute() * q.involute(), 1e-5));
+}
+
+#[test]
+fn matches_rot() {
+    let p: Rot = Rot::new(0.5, -0.3, 0.8, 0.1).normalize();
+    let q: Rot = Rot::from_angle_plane(0.7, Bivec3::new(0.2, 1.0, -0.4));
+    assert!((Multivec3::from(p) * Multivec3::from(q)).abs_diff_eq(Multivec3::from(p * q), 1e-6));
+
+    // The sandwich product R v R†
+    let v: Vec3 = Vec3::new(0.3, 0.9, -1.2);
+    let rotated: Multivec3 = Multivec3::from(p) * vector(v) * Multivec3::from(p.reverse());
+    assert!(rotated.vector().abs_diff_eq(p.rotate(v), 1e-6));
+    assert!(rotated.grade(3).abs_diff_eq(Multivec3::ZERO, 1e-6));
+    assert!((Multivec3::from(p) * Multivec3::from(p).reverse()).abs_diff_eq(Multivec3::ONE, 1e-6));
+}
+
+#[test]
+fn inner_and_dual() {
+    let v: Vec3 = Vec3::new(1.0, 2.0, 3.0);
+    let b: Bivec3 = Bivec3::new(0.5, -1.0, 2.0);
+    assert_eq!(vector(v).inner(Multivec3::from(b)).vector(), b.inner(v));
+    assert_eq!(Multivec3::from(b).dual().vector(), b.dual());
+
+    // The dual is the product with the inverse pseudoscalar
+    let p: Multivec3 = arbitrary();
+    assert!(p.dual().abs_diff_eq(p * -Multivec3::T012, 1e-6));
+    assert!(p.dual().dual().abs_diff_eq(-p, 1e-6));
+}
+
+#[test]
+fn norm_squared_is_scalar_of_product_with_reverse() {
+    let p: Multivec3 = arbitrary();
+    assert!(((p * p.reverse()).scalar() - p.norm_squared()).abs() < 1e-5);
+}
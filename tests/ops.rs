@@ -0,0 +1,104 @@
+use rgla::*;
+
+#[test]
+fn assign_ops_match_the_binary_ops() {
+    let a: Vec3 = Vec3::new(1.0, 2.0, 3.0);
+    let b: Vec3 = Vec3::new(-0.5, 4.0, 0.25);
+
+    let mut v: Vec3 = a;
+    v += b;
+    assert_eq!(v, a + b);
+    v -= b;
+    assert_eq!(v, a);
+    v *= 2.0;
+    assert_eq!(v, Vec3::new(2.0, 4.0, 6.0));
+    v /= 4.0;
+    assert_eq!(v, Vec3::new(0.5, 1.0, 1.5));
+    v += 1.0;
+    assert_eq!(v, Vec3::new(1.5, 2.0, 2.5));
+    v -= &b;
+    assert_eq!(v, Vec3::new(2.0, -2.0, 2.25));
+
+    let mut m: Mat3 = Mat3::I;
+    m *= Mat3::from_rotation(0.5);
+    m *= &Mat3::from_rotation(0.25);
+    assert!(m.abs_diff_eq(Mat3::from_rotation(0.75), 1e-6));
+    m += Mat3::I;
+    assert!(m.abs_diff_eq(Mat3::from_rotation(0.75) + Mat3::I, 1e-6));
+
+    let mut d: DVec4 = DVec4::new(1.0, 2.0, 3.0, 4.0);
+    d *= 0.5;
+    assert_eq!(d, DVec4::new(0.5, 1.0, 1.5, 2.0));
+}
+
+// The references are the point here
+#[test]
+#[allow(clippy::op_ref)]
+fn reference_ops_match_the_value_ops() {
+    let a: Vec3 = Vec3::new(1.0, 2.0, 3.0);
+    let b: Vec3 = Vec3::new(-0.5, 4.0, 0.25);
+    assert_eq!(&a + &b, a + b);
+    assert_eq!(&a - b, a - b);
+    assert_eq!(a * &b, a * b);
+    assert_eq!(&a / 2.0, a / 2.0);
+    assert_eq!(-&a, Vec3::new(-1.0, -2.0, -3.0));
+
+    let m: Mat4 = Mat4::from_translation(Vec3::new(5.0, 6.0, 7.0));
+    let v: Vec4 = Vec4::new(1.0, 2.0, 3.0, 1.0);
+    assert_eq!(&m * &v, m * v);
+    assert_eq!(&m * &m, m * m);
+    assert_eq!(-&m, m * -1.0);
+
+    let r: Rot = Rot::new(0.5, -0.3, 0.8, 0.1).normalize();
+    assert_eq!(&r * &r, r * r);
+}
+
+#[test]
+fn scalar_on_the_left() {
+    let v: Vec3 = Vec3::new(1.0, -2.0, 3.0);
+    assert_eq!(2.0 * v, v * 2.0);
+    assert_eq!(2.0 * &v, Vec3::new(2.0, -4.0, 6.0));
+    assert_eq!(0.5 * Vec2::new(2.0, 4.0), Vec2::new(1.0, 2.0));
+    assert_eq!(3.0 * DVec4::new(1.0, 0.0, -1.0, 2.0), DVec4::new(3.0, 0.0, -3.0, 6.0));
+    assert_eq!(2.0 * Mat2::I, Mat2::I * 2.0);
+    assert_eq!(2.0 * Bivec3::B01, Bivec3::new(2.0, 0.0, 0.0));
+}
+
+#[test]
+fn index_reads_and_writes_components() {
+    let mut v: Vec3 = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!((v[0], v[1], v[2]), (1.0, 2.0, 3.0));
+    v[1] = 5.0;
+    v[2] += 1.0;
+    assert_eq!(v, Vec3::new(1.0, 5.0, 4.0));
+
+    let mut w: Vec4 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    w[3] = -1.0;
+    assert_eq!(w.l, -1.0);
+
+    // Matrices index columns
+    let mut m: Mat4 = Mat4::from_translation(Vec3::new(5.0, 6.0, 7.0));
+    assert_eq!(m[3], Vec4::new(5.0, 6.0, 7.0, 1.0));
+    assert_eq!(m[3][1], 6.0);
+    m[0][0] = 2.0;
+    assert_eq!(m.col1, Vec4::new(2.0, 0.0, 0.0, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds_panics() {
+    let v: Vec2 = Vec2::new(1.0, 2.0);
+    let _ = v[2];
+}
+
+#[test]
+fn elementwise_mul_and_div() {
+    let a: Vec3 = Vec3::new(1.0, 2.0, 3.0);
+    let b: Vec3 = Vec3::new(2.0, 4.0, 0.5);
+    assert_eq!(a.mul_elem(b), Vec3::new(2.0, 8.0, 1.5));
+    assert_eq!(a.mul_elem(b).div_elem(b), a);
+
+    let m: Mat2 = Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(m.mul_elem(m), Mat2::from_cols_array(&[1.0, 4.0, 9.0, 16.0]));
+    assert_eq!(m.div_elem(m), Mat2::from_cols_array(&[1.0; 4]));
+}
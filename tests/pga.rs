@@ -0,0 +1,114 @@
+use rgla::{Bivec3, Line, Motor, Plane, Point, Rot, Vec3, Vec4};
+use std::f32::consts::FRAC_PI_2;
+
+fn arbitrary_motor() -> Motor {
+    Motor::from_rot_translation(Rot::new(0.5, -0.3, 0.8, 0.1).normalize(), Vec3::new(1.0, -2.0, 0.5))
+}
+
+fn moved(motor: Motor, point: Vec3) -> Vec3 {
+    motor.transform_point(Point::from(point)).to_vec3()
+}
+
+#[test]
+fn try_normalize() {
+    assert_eq!(Point::new(2.0, 4.0, 6.0, 2.0).try_normalize(), Some(Point::new(1.0, 2.0, 3.0, 1.0)));
+    assert_eq!(Point::from_direction(Vec3::I).try_normalize(), None);
+
+    let line: Line = Line::from_point_dir(Vec3::J, Vec3::new(0.0, 0.0, 2.0));
+    assert!((line.try_normalize().unwrap().norm() - 1.0).abs() < 1e-6);
+    assert_eq!(Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0).try_normalize(), None);
+
+    assert_eq!(Plane::new(0.0, 2.0, 0.0, -4.0).try_normalize(), Some(Plane::new(0.0, 1.0, 0.0, -2.0)));
+    assert_eq!(Plane::new(0.0, 0.0, 0.0, 1.0).try_normalize(), None);
+
+    assert_eq!(Motor::IDENTITY.try_normalize(), Some(Motor::IDENTITY));
+    assert_eq!((Motor::IDENTITY * 2.0).try_normalize(), Some(Motor::IDENTITY));
+    assert_eq!(Motor::new(0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0).try_normalize(), None);
+}
+
+#[test]
+fn rotates_then_translates() {
+    let rot: Rot = Rot::new(0.5, -0.3, 0.8, 0.1).normalize();
+    let translation: Vec3 = Vec3::new(1.0, -2.0, 0.5);
+    let m: Motor = Motor::from_rot_translation(rot, translation);
+    let p: Vec3 = Vec3::new(0.3, 0.9, -1.2);
+    assert!(moved(m, p).abs_diff_eq(rot.rotate(p) + translation, 1e-5));
+    assert!(moved(Motor::from_translation(translation), p).abs_diff_eq(p + translation, 1e-6));
+    assert!(m.rot().abs_diff_eq(rot, 1e-6));
+    assert!(m.translation().abs_diff_eq(translation, 1e-5));
+
+    // Directions only rotate
+    let dir: Point = m.transform_point(Point::from_direction(p));
+    assert_eq!(dir.w, 0.0);
+    assert!(Vec3::new(dir.i, dir.j, dir.k).abs_diff_eq(rot.rotate(p), 1e-5));
+}
+
+#[test]
+fn product_applies_the_right_motor_first() {
+    let a: Motor = arbitrary_motor();
+    let b: Motor = Motor::from_line_angle(Line::from_point_dir(Vec3::I, Vec3::J), 0.8);
+    let p: Vec3 = Vec3::new(0.3, 0.9, -1.2);
+    assert!(moved(a * b, p).abs_diff_eq(moved(a, moved(b, p)), 1e-5));
+    assert!(moved(a.inverse(), moved(a, p)).abs_diff_eq(p, 1e-5));
+    assert!((a * a.reverse()).abs_diff_eq(Motor::IDENTITY, 1e-5));
+}
+
+#[test]
+fn turns_around_a_line() {
+    // A line through (0, 1, 0) pointing along K, towards the viewer of the I, J plane
+    let line: Line = Line::from_point_dir(Vec3::J, Vec3::K);
+    let m: Motor = Motor::from_line_angle(line, FRAC_PI_2);
+    assert!(moved(m, Vec3::new(1.0, 1.0, 0.0)).abs_diff_eq(Vec3::new(0.0, 2.0, 0.0), 1e-6));
+    assert!(moved(m, Vec3::new(0.0, 1.0, 5.0)).abs_diff_eq(Vec3::new(0.0, 1.0, 5.0), 1e-6));
+    assert!(m.transform_line(line).abs_diff_eq(line, 1e-6));
+}
+
+#[test]
+fn lines_and_planes_follow_their_points() {
+    let m: Motor = arbitrary_motor();
+    let (a, b, c) = (Vec3::new(1.0, 0.0, 2.0), Vec3::new(-1.0, 3.0, 0.5), Vec3::new(0.0, 1.0, -1.0));
+
+    let line: Line = m.transform_line(Line::from_points(a, b));
+    assert!(line.abs_diff_eq(Line::from_points(moved(m, a), moved(m, b)), 1e-5));
+
+    let plane: Plane = m.transform_plane(Plane::from_points(a, b, c).normalize());
+    for p in [a, b, c] {
+        assert!(plane.dist(moved(m, p)).abs() < 1e-5);
+    }
+    let offset: Vec3 = a + Plane::from_points(a, b, c).normalize().normal() * 2.0;
+    assert!((plane.dist(moved(m, offset)) - 2.0).abs() < 1e-5);
+}
+
+#[test]
+fn to_mat4_matches_transform_point() {
+    let m: Motor = arbitrary_motor();
+    let p: Vec3 = Vec3::new(0.3, 0.9, -1.2);
+    let q: Vec4 = m.to_mat4() * Vec4::new(p.i, p.j, p.k, 1.0);
+    assert!(Vec3::new(q.i, q.j, q.k).abs_diff_eq(moved(m, p), 1e-5));
+}
+
+#[test]
+fn exp_and_log_round_trip() {
+    let screw: Line = Line::new(0.3, -0.2, 0.5, 1.0, 0.4, -0.7);
+    assert!(screw.exp().log().abs_diff_eq(screw, 1e-5));
+    let m: Motor = arbitrary_motor();
+    assert!(m.log().exp().abs_diff_eq(m, 1e-5));
+
+    // Lines at infinity are pure translations, to second order too
+    let ideal: Line = Line::new(0.0, 0.0, 0.0, 1.0, 2.0, 3.0);
+    assert!(ideal.exp().abs_diff_eq(Motor::from_translation(Vec3::new(-2.0, -4.0, -6.0)), 1e-6));
+}
+
+#[test]
+fn sclerp_moves_along_one_screw() {
+    let line: Line = Line::from_point_dir(Vec3::new(1.0, 0.0, 2.0), Vec3::new(0.0, 1.0, 1.0)).normalize();
+    let start: Motor = Motor::from_rot(Rot::from_angle_plane(0.3, Bivec3::B02));
+    let step = |angle: f32, slide: f32| {
+        Motor::from_translation(line.direction() * slide) * Motor::from_line_angle(line, angle)
+    };
+    let end: Motor = step(1.2, 2.0) * start;
+    assert!(start.sclerp(end, 0.0).abs_diff_eq(start, 1e-5));
+    assert!(start.sclerp(end, 1.0).abs_diff_eq(end, 1e-5));
+    assert!(start.sclerp(end, 0.5).abs_diff_eq(step(0.6, 1.0) * start, 1e-5));
+    assert!(start.sclerp(-end, 0.5).abs_diff_eq(step(0.6, 1.0) * start, 1e-5));
+}
@@ -0,0 +1,38 @@
+use rgla::*;
+
+// Every f32 value is exactly representable as f64, so these come back equal
+#[test]
+fn f32_to_f64_and_back_is_exact() {
+    let v: Vec4 = Vec4::new(0.1, -2.5, 1e-7, 3e7);
+    assert_eq!(v.as_f64().as_f32(), v);
+    assert_eq!(v.as_f64(), DVec4::new(0.1f32 as f64, -2.5, 1e-7f32 as f64, 3e7));
+    assert_eq!(Vec2::new(0.1, 0.2).as_f64().as_f32(), Vec2::new(0.1, 0.2));
+    assert_eq!(Vec3::new(0.1, 0.2, 0.3).as_f64().as_f32(), Vec3::new(0.1, 0.2, 0.3));
+
+    let rot: Rot = Rot::new(0.5, -0.3, 0.8, 0.1).normalize();
+    assert_eq!(rot.as_f64().as_f32(), rot);
+    assert_eq!(Bivec3::new(0.1, 0.2, 0.3).as_f64().as_f32(), Bivec3::new(0.1, 0.2, 0.3));
+
+    let mat: Mat4 = Mat4::perspective_left(1.2, 1.5, 0.1, 100.0);
+    assert_eq!(mat.as_f64().as_f32(), mat);
+    assert_eq!(rot.to_mat3().as_f64().as_f32(), rot.to_mat3());
+    assert_eq!(Mat2::from_cols_array(&[0.1, 0.2, 0.3, 0.4]).as_f64().as_f32(), Mat2::from_cols_array(&[0.1, 0.2, 0.3, 0.4]));
+
+    let affine: Affine3 = Affine3::new(Vec3::new(2.0, 2.0, 2.0), rot, Vec3::new(0.1, 0.2, 0.3));
+    assert_eq!(affine.as_f64().as_f32(), affine);
+    let motor: Motor = Motor::from_rot_translation(rot, Vec3::new(0.1, 0.2, 0.3));
+    assert_eq!(motor.as_f64().as_f32(), motor);
+}
+
+#[test]
+fn f64_types_agree_with_f32() {
+    let rot: Rot = Rot::new(0.5, -0.3, 0.8, 0.1).normalize();
+    let v: Vec3 = Vec3::new(0.3, 0.9, -1.2);
+    let rotated: DVec3 = rot.as_f64().rotate(v.as_f64());
+    assert!(rotated.as_f32().abs_diff_eq(rot.rotate(v), 1e-6));
+
+    let mat: DMat4 = DMat4::look_at_right(DVec3::new(1.0, 2.0, 3.0), DVec3::ZERO, DVec3::J);
+    assert!(mat.as_f32().abs_diff_eq(Mat4::look_at_right(Vec3::new(1.0, 2.0, 3.0), Vec3::ZERO, Vec3::J), 1e-6));
+    // Narrowing rounds to the nearest f32
+    assert_eq!(DVec2::new(0.1, 1.0 / 3.0).as_f32(), Vec2::new(0.1, 1.0 / 3.0));
+}
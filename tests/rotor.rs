@@ -0,0 +1,246 @@
+use rgla::{Bivec3, Mat3, Rot, Vec3};
+use std::f32::consts::{FRAC_PI_2, PI};
+
+fn arbitrary_rot() -> Rot {
+    Rot::new(0.5, -0.3, 0.8, 0.1).normalize()
+}
+
+#[test]
+fn rotate_quarter_turn_in_each_plane() {
+    // Each plane turns its first vector towards its second
+    let r: Rot = Rot::from_angle_plane(FRAC_PI_2, Bivec3::B01);
+    assert!(r.rotate(Vec3::I).abs_diff_eq(Vec3::J, 1e-6));
+    assert!(r.rotate(Vec3::J).abs_diff_eq(-Vec3::I, 1e-6));
+    assert!(r.rotate(Vec3::K).abs_diff_eq(Vec3::K, 1e-6));
+
+    let r: Rot = Rot::from_angle_plane(FRAC_PI_2, Bivec3::B02);
+    assert!(r.rotate(Vec3::I).abs_diff_eq(Vec3::K, 1e-6));
+    assert!(r.rotate(Vec3::J).abs_diff_eq(Vec3::J, 1e-6));
+
+    let r: Rot = Rot::from_angle_plane(FRAC_PI_2, Bivec3::B12);
+    assert!(r.rotate(Vec3::J).abs_diff_eq(Vec3::K, 1e-6));
+    assert!(r.rotate(Vec3::I).abs_diff_eq(Vec3::I, 1e-6));
+}
+
+#[test]
+fn rotate_keeps_length_and_is_undone_by_reverse() {
+    let r: Rot = arbitrary_rot();
+    let v: Vec3 = Vec3::new(1.0, -2.0, 0.5);
+    let rotated: Vec3 = r.rotate(v);
+    assert!((rotated.norm() - v.norm()).abs() < 1e-5);
+    assert!(r.reverse().rotate(rotated).abs_diff_eq(v, 1e-5));
+    assert!(r.inverse().rotate(rotated).abs_diff_eq(v, 1e-5));
+}
+
+#[test]
+fn rotate_matches_product_and_matrix() {
+    let p: Rot = arbitrary_rot();
+    let q: Rot = Rot::from_angle_plane(0.7, Bivec3::new(0.2, 1.0, -0.4));
+    let v: Vec3 = Vec3::new(0.3, 0.9, -1.2);
+
+    // p * q applies q first
+    assert!((p * q).rotate(v).abs_diff_eq(p.rotate(q.rotate(v)), 1e-5));
+    assert!((Mat3::from_rot(p) * v).abs_diff_eq(p.rotate(v), 1e-5));
+}
+
+// Trace near -1 picks the branch of the largest diagonal term, trace > 0 the first
+#[test]
+fn from_mat3_round_trips_through_every_branch() {
+    let rots: [Rot; 5] = [
+        // trace > 0
+        arbitrary_rot(),
+        // m00 largest, turns about I
+        Rot::from_angle_plane(PI, Bivec3::B12),
+        Rot::from_angle_plane(PI * 0.97, Bivec3::new(0.1, -0.2, 1.0)),
+        // m11 largest, turns about J
+        Rot::from_angle_plane(PI * 0.97, Bivec3::new(0.2, 1.0, 0.1)),
+        // m22 largest, turns about K
+        Rot::from_angle_plane(PI, Bivec3::new(1.0, 0.1, -0.2)),
+    ];
+    for r in rots {
+        let mat: Mat3 = r.to_mat3();
+        let back: Rot = Rot::from_mat3(mat);
+        assert!(back.abs_diff_eq(r, 1e-5), "{:?} became {:?}", r, back);
+        assert!(back.to_mat3().abs_diff_eq(mat, 1e-5));
+    }
+}
+
+#[test]
+fn from_mat3_identity() {
+    assert_eq!(Rot::from_mat3(Mat3::I), Rot::IDENTITY);
+}
+
+#[test]
+fn slerp_endpoints_and_midpoint() {
+    let p: Rot = Rot::from_angle_plane(0.2, Bivec3::B01);
+    let q: Rot = Rot::from_angle_plane(1.4, Bivec3::B01);
+    assert!(p.slerp(q, 0.0).abs_diff_eq(p, 1e-6));
+    assert!(p.slerp(q, 1.0).abs_diff_eq(q, 1e-6));
+    assert!(p.slerp(q, 0.5).abs_diff_eq(Rot::from_angle_plane(0.8, Bivec3::B01), 1e-6));
+    assert!(p.slerp(q, 0.25).abs_diff_eq(Rot::from_angle_plane(0.5, Bivec3::B01), 1e-6));
+    assert!(p.nlerp(q, 0.5).abs_diff_eq(Rot::from_angle_plane(0.8, Bivec3::B01), 1e-6));
+}
+
+#[test]
+fn slerp_takes_the_shortest_arc() {
+    let p: Rot = Rot::from_angle_plane(0.3, Bivec3::B02);
+    let q: Rot = Rot::from_angle_plane(-0.5, Bivec3::B02);
+    for t in [0.1, 0.5, 0.9] {
+        let expected: Rot = Rot::from_angle_plane(0.3 - 0.8 * t, Bivec3::B02);
+        assert!(p.slerp(q, t).abs_diff_eq(expected, 1e-6));
+        assert!(p.slerp(-q, t).abs_diff_eq(expected, 1e-6));
+    }
+}
+
+#[test]
+fn squad_hits_the_keyframes() {
+    let keys: [Rot; 4] = [
+        Rot::IDENTITY,
+        Rot::from_angle_plane(0.6, Bivec3::B01),
+        Rot::from_angle_plane(0.9, Bivec3::new(0.3, 1.0, 0.2)),
+        arbitrary_rot(),
+    ];
+    let c1: Rot = Rot::squad_control(keys[0], keys[1], keys[2]);
+    let c2: Rot = Rot::squad_control(keys[1], keys[2], keys[3]);
+    assert!(keys[1].squad(keys[2], c1, c2, 0.0).abs_diff_eq(keys[1], 1e-5));
+    assert!(keys[1].squad(keys[2], c1, c2, 1.0).abs_diff_eq(keys[2], 1e-5));
+    assert!(keys[1].squad(keys[2], c1, c2, 0.5).is_normalized(1e-5));
+}
+
+#[test]
+fn squad_in_one_plane_at_constant_speed_is_slerp() {
+    let keys: [Rot; 4] = [0.0, 0.5, 1.0, 1.5].map(|angle| Rot::from_angle_plane(angle, Bivec3::B12));
+    let c1: Rot = Rot::squad_control(keys[0], keys[1], keys[2]);
+    let c2: Rot = Rot::squad_control(keys[1], keys[2], keys[3]);
+    for t in [0.2, 0.5, 0.7] {
+        assert!(keys[1].squad(keys[2], c1, c2, t).abs_diff_eq(keys[1].slerp(keys[2], t), 1e-5));
+    }
+}
+
+// squad follows the signs it is given, so opposite rotors are a full turn apart
+#[test]
+fn squad_between_opposite_rotors_stays_normalized() {
+    let p: Rot = arbitrary_rot();
+    for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        let r: Rot = p.squad(-p, p, -p, t);
+        assert!(r.is_normalized(1e-5), "{:?} at {}", r, t);
+    }
+    assert_eq!(p.squad(-p, p, -p, 0.0).to_array(), p.to_array());
+    let end: [f32; 4] = p.squad(-p, p, -p, 1.0).to_array();
+    for (x, y) in end.iter().zip((-p).to_array()) {
+        assert!((x - y).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn normalize_fallbacks() {
+    let r: Rot = Rot::new(2.0, 0.0, 0.0, 0.0);
+    assert_eq!(r.try_normalize(), Some(Rot::IDENTITY));
+    assert_eq!(r.normalize_or_zero(), Rot::IDENTITY);
+
+    let zero: Rot = Rot::new(0.0, 0.0, 0.0, 0.0);
+    assert_eq!(zero.try_normalize(), None);
+    assert_eq!(zero.normalize_or_identity(), Rot::IDENTITY);
+    assert_eq!(zero.normalize_or_zero(), zero);
+    assert_eq!(Rot::new(f32::NAN, 0.0, 0.0, 0.0).try_normalize(), None);
+}
+
+#[test]
+fn exp_and_log_round_trip() {
+    for b in [
+        Bivec3::new(0.3, -0.5, 0.2),
+        Bivec3::new(1.0, 2.0, -0.5),
+        Bivec3::new(2e-5, -3e-5, 1e-5),
+        Bivec3::new(0.0, 0.0, 0.0),
+    ] {
+        assert!(b.exp().log().abs_diff_eq(b, 1e-6), "{:?}", b);
+        assert!(b.exp().is_normalized(1e-6));
+    }
+    let r: Rot = arbitrary_rot();
+    assert!(r.log().exp().abs_diff_eq(r, 1e-6));
+
+    // exp of -angle / 2 times a unit plane is from_angle_plane
+    let plane: Bivec3 = Bivec3::new(0.3, -1.0, 0.5).normalize();
+    assert!((plane * -0.4).exp().abs_diff_eq(Rot::from_angle_plane(0.8, plane), 1e-6));
+}
+
+// Near a full turn a is close to -1 and the bivector part is tiny
+#[test]
+fn log_of_an_almost_full_turn() {
+    for angle in [2.0 * PI - 1e-3, 2.0 * PI - 1e-5] {
+        let r: Rot = Rot::from_angle_plane(angle, Bivec3::B01);
+        assert!(r.a < 0.0);
+        let back: [f32; 4] = r.log().exp().to_array();
+        for (x, y) in back.iter().zip(r.to_array()) {
+            assert!((x - y).abs() < 1e-5, "{:?} became {:?}", r, back);
+        }
+    }
+    let full: [f32; 4] = Rot::new(-1.0, 0.0, 0.0, 0.0).log().exp().to_array();
+    assert!((full[0] + 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn from_vecs_maps_the_direction() {
+    let pairs: [(Vec3, Vec3); 4] = [
+        (Vec3::I, Vec3::J),
+        (Vec3::new(2.0, -1.0, 0.5), Vec3::new(0.1, 0.3, -4.0)),
+        (Vec3::new(0.0, 0.0, 10.0), Vec3::new(0.0, 0.01, 0.0)),
+        (Vec3::new(1.0, 2.0, 3.0), Vec3::new(2.0, 4.0, 6.0)),
+    ];
+    for (from, to) in pairs {
+        let r: Rot = Rot::from_vecs(from, to);
+        assert!(r.is_normalized(1e-6));
+        assert!(r.rotate(from.normalize()).abs_diff_eq(to.normalize(), 1e-6), "{:?} to {:?}", from, to);
+    }
+    // Shortest arc, so the normal of both is left alone
+    let r: Rot = Rot::from_vecs(Vec3::new(3.0, 0.0, 0.0), Vec3::new(0.0, 0.5, 0.0));
+    assert!(r.abs_diff_eq(Rot::from_angle_plane(FRAC_PI_2, Bivec3::B01), 1e-6));
+}
+
+#[test]
+fn from_vecs_of_opposite_vectors() {
+    for v in [Vec3::I, Vec3::new(1.0, 2.0, 3.0), Vec3::new(-0.3, 0.0, 7.0)] {
+        let r: Rot = Rot::from_vecs(v, -2.0 * v);
+        assert!(r.to_array().iter().all(|x| x.is_finite()));
+        assert!(r.is_normalized(1e-6));
+        assert!(r.rotate(v).abs_diff_eq(-v, 1e-5), "{:?} became {:?}", v, r.rotate(v));
+    }
+}
+
+// The rotor angle is tiny here, it must not come from 1 + cos losing digits
+#[test]
+fn from_vecs_of_almost_opposite_vectors() {
+    let from: Vec3 = Vec3::new(1.0, 1e-3, 0.0);
+    let r: Rot = Rot::from_vecs(from, -Vec3::I);
+    let to: Vec3 = r.rotate(from.normalize());
+    assert!(to.abs_diff_eq(-Vec3::I, 1e-6), "{:?}", to);
+    assert!(Rot::from_vecs(-Vec3::I, from).rotate(-Vec3::I).abs_diff_eq(from.normalize(), 1e-6));
+}
+
+#[test]
+fn from_vecs_of_a_zero_vector_is_identity() {
+    assert_eq!(Rot::from_vecs(Vec3::ZERO, Vec3::J), Rot::IDENTITY);
+    assert_eq!(Rot::from_vecs(Vec3::J, Vec3::ZERO), Rot::IDENTITY);
+    assert_eq!(Rot::from_vecs(Vec3::ZERO, Vec3::ZERO), Rot::IDENTITY);
+}
+
+#[test]
+fn from_vecs_with_up_keeps_up_upright() {
+    let up: Vec3 = Vec3::new(0.1, 1.0, 0.2);
+    let pairs: [(Vec3, Vec3); 3] = [
+        (Vec3::new(0.0, 0.0, -2.0), Vec3::new(1.0, 0.3, 0.0)),
+        (Vec3::new(1.0, -0.5, 0.5), Vec3::new(-0.2, 0.1, 3.0)),
+        (Vec3::I, -Vec3::I),
+    ];
+    for (from, to) in pairs {
+        let r: Rot = Rot::from_vecs_with_up(from, to, up);
+        assert!(r.is_normalized(1e-5));
+        assert!(r.rotate(from.normalize()).abs_diff_eq(to.normalize(), 1e-5));
+        let from_up: Vec3 = up.reject_from(from).normalize();
+        let to_up: Vec3 = up.reject_from(to).normalize();
+        assert!(r.rotate(from_up).abs_diff_eq(to_up, 1e-5), "{:?} to {:?}", from, to);
+    }
+    // Up along a vector has no part to keep
+    let r: Rot = Rot::from_vecs_with_up(Vec3::J, Vec3::I, Vec3::J);
+    assert_eq!(r, Rot::from_vecs(Vec3::J, Vec3::I));
+}
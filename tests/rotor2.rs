@@ -0,0 +1,72 @@
+use rgla::{Bivec2, Mat2, Mat3, Rot2, Vec2, Vec3};
+use std::f32::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn rotate_turns_i_towards_j() {
+    let r: Rot2 = Rot2::from_angle(FRAC_PI_2);
+    assert!(r.rotate(Vec2::I).abs_diff_eq(Vec2::J, 1e-6));
+    assert!(r.rotate(Vec2::J).abs_diff_eq(-Vec2::I, 1e-6));
+    assert!(r.reverse().rotate(r.rotate(Vec2::new(0.3, -2.0))).abs_diff_eq(Vec2::new(0.3, -2.0), 1e-6));
+}
+
+#[test]
+fn angle_round_trips() {
+    for angle in [0.0, 0.4, -1.2, 2.5, -3.0] {
+        assert!((Rot2::from_angle(angle).angle() - angle).abs() < 1e-6, "{}", angle);
+    }
+    assert!((Rot2::from_angle(PI).angle().abs() - PI).abs() < 1e-6);
+    // Past pi it wraps around, and -r is the same turn
+    assert!((Rot2::from_angle(1.5 * PI).angle() + FRAC_PI_2).abs() < 1e-6);
+    assert!(((-Rot2::from_angle(0.4)).angle() - 0.4).abs() < 1e-6);
+}
+
+#[test]
+fn products_add_angles() {
+    let r: Rot2 = Rot2::from_angle(0.4) * Rot2::from_angle(1.1);
+    assert!(r.abs_diff_eq(Rot2::from_angle(1.5), 1e-6));
+    assert!((Rot2::from_angle(0.7) * Rot2::from_angle(0.7).inverse()).abs_diff_eq(Rot2::IDENTITY, 1e-6));
+}
+
+#[test]
+fn matches_the_matrices() {
+    let r: Rot2 = Rot2::from_angle(0.9);
+    let v: Vec2 = Vec2::new(0.3, -2.0);
+    assert!((r.to_mat2() * v).abs_diff_eq(r.rotate(v), 1e-6));
+    assert!(r.to_mat3().abs_diff_eq(Mat3::from_rotation(0.9), 1e-6));
+    assert!((r.to_mat3() * Vec3::new(v.i, v.j, 1.0)).abs_diff_eq(Vec3::new(r.rotate(v).i, r.rotate(v).j, 1.0), 1e-6));
+    assert!((r.to_mat2() * r.reverse().to_mat2()).abs_diff_eq(Mat2::I, 1e-6));
+}
+
+#[test]
+fn from_vecs() {
+    let from: Vec2 = Vec2::new(2.0, 0.0);
+    let to: Vec2 = Vec2::new(-1.0, 1.0);
+    let r: Rot2 = Rot2::from_vecs(from, to);
+    assert!((r.angle() - 0.75 * PI).abs() < 1e-6);
+    assert!(r.rotate(from).normalize().abs_diff_eq(to.normalize(), 1e-6));
+
+    let half: Rot2 = Rot2::from_vecs(Vec2::I, -Vec2::I * 3.0);
+    assert!(half.rotate(Vec2::I).abs_diff_eq(-Vec2::I, 1e-6));
+    assert_eq!(Rot2::from_vecs(Vec2::ZERO, Vec2::I), Rot2::IDENTITY);
+
+    // Almost opposite
+    let from: Vec2 = Vec2::new(1.0, 1e-3);
+    assert!(Rot2::from_vecs(from, -Vec2::I).rotate(from.normalize()).abs_diff_eq(-Vec2::I, 1e-6));
+}
+
+#[test]
+fn exp_log_and_slerp() {
+    let b: Bivec2 = Bivec2::new(0.6);
+    assert!(b.exp().log().abs_diff_eq(b, 1e-6));
+    assert!(Rot2::from_angle(1.0).abs_diff_eq(Bivec2::new(-0.5).exp(), 1e-6));
+
+    let p: Rot2 = Rot2::from_angle(0.2);
+    let q: Rot2 = Rot2::from_angle(1.4);
+    assert!(p.slerp(q, 0.25).abs_diff_eq(Rot2::from_angle(0.5), 1e-6));
+    assert!(p.slerp(-q, 0.25).abs_diff_eq(Rot2::from_angle(0.5), 1e-6));
+    assert!(p.nlerp(q, 0.5).abs_diff_eq(Rot2::from_angle(0.8), 1e-6));
+
+    // The short way round from 3 to -3 crosses pi
+    let r: Rot2 = Rot2::from_angle(3.0).slerp(Rot2::from_angle(-3.0), 0.5);
+    assert!(r.abs_diff_eq(Rot2::from_angle(PI), 1e-6));
+}
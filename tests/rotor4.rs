@@ -0,0 +1,70 @@
+use rgla::{Bivec3, Bivec4, Mat4, Rot, Rot4, Vec3, Vec4};
+use std::f32::consts::FRAC_PI_2;
+
+fn double() -> Rot4 {
+    Rot4::from_angle_plane(0.7, Bivec4::B01) * Rot4::from_angle_plane(-1.3, Bivec4::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0))
+}
+
+#[test]
+fn quarter_turns_in_a_plane() {
+    let r: Rot4 = Rot4::from_angle_plane(FRAC_PI_2, Bivec4::B01);
+    assert!(r.rotate(Vec4::I).abs_diff_eq(Vec4::J, 1e-6));
+    assert!(r.rotate(Vec4::K).abs_diff_eq(Vec4::K, 1e-6));
+    assert!(r.rotate(Vec4::L).abs_diff_eq(Vec4::L, 1e-6));
+
+    // K ^ L, a plane with no 3D counterpart
+    let r: Rot4 = Rot4::from_angle_plane(FRAC_PI_2, Bivec4::wedge(Vec4::K, Vec4::L));
+    assert!(r.rotate(Vec4::K).abs_diff_eq(Vec4::L, 1e-6));
+    assert!(r.rotate(Vec4::L).abs_diff_eq(-Vec4::K, 1e-6));
+    assert!(r.rotate(Vec4::I).abs_diff_eq(Vec4::I, 1e-6));
+}
+
+#[test]
+fn from_rot_matches_the_3d_rotation() {
+    let rot: Rot = Rot::from_angle_plane(0.9, Bivec3::new(0.3, -1.0, 0.5));
+    let r: Rot4 = Rot4::from_rot(rot);
+    let v: Vec4 = Vec4::new(0.3, 0.9, -1.2, 2.0);
+    let expected: Vec3 = rot.rotate(Vec3::new(v.i, v.j, v.k));
+    assert!(r.rotate(v).abs_diff_eq(Vec4::new(expected.i, expected.j, expected.k, v.l), 1e-6));
+}
+
+#[test]
+fn double_rotation_turns_both_planes() {
+    let r: Rot4 = double();
+    assert!(r.is_normalized(1e-6));
+    assert!(r.rotate(Vec4::I).abs_diff_eq(Vec4::new(0.7f32.cos(), 0.7f32.sin(), 0.0, 0.0), 1e-6));
+    assert!(r.rotate(Vec4::K).abs_diff_eq(Vec4::new(0.0, 0.0, 1.3f32.cos(), -1.3f32.sin()), 1e-6));
+    assert!(r.abs_diff_eq((Bivec4::B01 * -0.35 + Bivec4::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0) * 0.65).exp(), 1e-6));
+}
+
+#[test]
+fn isoclinic_rotation_turns_every_vector_by_the_same_angle() {
+    let plane: Bivec4 = Bivec4::B01 + Bivec4::B01.dual();
+    assert_eq!(plane.wedge_self(), 2.0);
+    let r: Rot4 = (plane * -0.4).exp();
+    for v in [Vec4::I, Vec4::new(0.3, 0.9, -1.2, 2.0), Vec4::new(1.0, 0.0, 0.0, -1.0)] {
+        let cos: f32 = (r.rotate(v) * v) / (v * v);
+        assert!((cos - 0.8f32.cos()).abs() < 1e-6, "{:?}", v);
+    }
+}
+
+#[test]
+fn matches_the_matrix_and_products() {
+    let p: Rot4 = double();
+    let q: Rot4 = Rot4::from_angle_plane(0.5, Bivec4::wedge(Vec4::new(1.0, 0.5, 0.0, -1.0), Vec4::new(0.0, 2.0, 1.0, 0.3)));
+    let q: Rot4 = (Bivec4::new(0.1, 0.0, 0.5, -0.3, 0.2, 0.4) * 0.5).exp() * q;
+    let v: Vec4 = Vec4::new(0.3, 0.9, -1.2, 2.0);
+    assert!((p.to_mat4() * v).abs_diff_eq(p.rotate(v), 1e-5));
+    assert!((p * q).rotate(v).abs_diff_eq(p.rotate(q.rotate(v)), 1e-5));
+    assert!(q.reverse().rotate(q.rotate(v)).abs_diff_eq(v, 1e-5));
+    assert!(((q.rotate(v) * q.rotate(v)) - v * v).abs() < 1e-5);
+    assert!((q.to_mat4() * q.to_mat4().transpose()).abs_diff_eq(Mat4::I, 1e-5));
+}
+
+#[test]
+fn nlerp_midpoint() {
+    let p: Rot4 = Rot4::from_angle_plane(0.2, Bivec4::B02);
+    let q: Rot4 = Rot4::from_angle_plane(1.4, Bivec4::B02);
+    assert!(p.nlerp(q, 0.5).abs_diff_eq(Rot4::from_angle_plane(0.8, Bivec4::B02), 1e-6));
+    assert!(p.nlerp(-q, 0.5).abs_diff_eq(Rot4::from_angle_plane(0.8, Bivec4::B02), 1e-6));
+}
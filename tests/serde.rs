@@ -0,0 +1,78 @@
+#![cfg(feature = "serde")]
+
+use rgla::*;
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::Debug;
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) {
+    let json: String = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value, "json {}", json);
+
+    let bytes: Vec<u8> = bincode::serialize(&value).unwrap();
+    assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), value);
+}
+
+#[test]
+fn vectors_round_trip() {
+    round_trip(Vec2::new(1.0, -2.5));
+    round_trip(Vec3::new(1.0, -2.5, 3.25));
+    round_trip(Vec4::new(1.0, -2.5, 3.25, 0.125));
+    round_trip(DVec3::new(0.1, 0.2, 0.3));
+}
+
+#[test]
+fn bivectors_and_rotors_round_trip() {
+    round_trip(Bivec2::new(0.75));
+    round_trip(Bivec3::new(0.5, -1.0, 2.0));
+    round_trip(Bivec4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+    round_trip(Rot2::from_angle(0.3));
+    round_trip(Rot::new(0.5, -0.3, 0.8, 0.1).normalize());
+    round_trip(DRot::new(0.5, -0.3, 0.8, 0.1).normalize());
+    round_trip(Rot4::new(0.5, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7));
+    round_trip(Multivec3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0));
+}
+
+#[test]
+fn matrices_and_transforms_round_trip() {
+    round_trip(Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]));
+    round_trip(Rot::new(0.5, -0.3, 0.8, 0.1).normalize().to_mat3());
+    round_trip(Mat4::perspective_left(1.2, 1.5, 0.1, 100.0));
+    round_trip(DMat4::look_at_right(DVec3::new(1.0, 2.0, 3.0), DVec3::ZERO, DVec3::J));
+    round_trip(Affine3::new(Vec3::new(2.0, 2.0, 2.0), Rot::new(0.5, -0.3, 0.8, 0.1).normalize(), Vec3::new(1.0, -2.0, 3.0)));
+}
+
+#[test]
+fn pga_round_trips() {
+    round_trip(Point::new(1.0, 2.0, 3.0, 1.0));
+    round_trip(Line::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6));
+    round_trip(Plane::new(0.0, 1.0, 0.0, -2.0));
+    round_trip(Motor::new(1.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7));
+    round_trip(DMotor::new(1.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7));
+}
+
+#[test]
+fn json_shape() {
+    assert_eq!(serde_json::to_string(&Vec3::new(1.0, 2.0, 3.0)).unwrap(), "[1.0,2.0,3.0]");
+    assert_eq!(serde_json::to_string(&Bivec2::new(0.5)).unwrap(), "[0.5]");
+    assert_eq!(serde_json::to_string(&Rot::IDENTITY).unwrap(), "[1.0,0.0,0.0,0.0]");
+
+    // Matrices are flat arrays of their columns
+    let m: Mat3 = Mat3::from_rows(
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(4.0, 5.0, 6.0),
+        Vec3::new(7.0, 8.0, 9.0),
+    );
+    assert_eq!(serde_json::to_string(&m).unwrap(), "[1.0,4.0,7.0,2.0,5.0,8.0,3.0,6.0,9.0]");
+    let t: Mat4 = Mat4::from_translation(Vec3::new(5.0, 6.0, 7.0));
+    assert_eq!(
+        serde_json::to_string(&t).unwrap(),
+        "[1.0,0.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,0.0,1.0,0.0,5.0,6.0,7.0,1.0]"
+    );
+    assert_eq!(serde_json::from_str::<Mat3>("[1,4,7,2,5,8,3,6,9]").unwrap(), m);
+}
+
+#[test]
+fn wrong_length_is_an_error() {
+    assert!(serde_json::from_str::<Vec3>("[1.0,2.0]").is_err());
+    assert!(serde_json::from_str::<Mat2>("[1.0,2.0,3.0]").is_err());
+}
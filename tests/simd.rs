@@ -0,0 +1,38 @@
+#![cfg(feature = "simd")]
+
+use rgla::{Mat4, Rot, Vec3, Vec4};
+
+fn sample() -> Mat4 {
+    Mat4::from_translation(Vec3::new(1.0, -2.0, 3.0))
+        * Rot::new(0.5, -0.3, 0.8, 0.1).normalize().to_mat4()
+        * Mat4::from_scale(Vec3::new(2.0, 0.5, 3.0))
+}
+
+// The columns weighted by the components, without going through the simd path
+fn combine(mat: Mat4, vec: Vec4) -> Vec4 {
+    mat.col1 * vec.i + mat.col2 * vec.j + mat.col3 * vec.k + mat.col4 * vec.l
+}
+
+#[test]
+fn mat4_mul_vec4_matches_the_column_combination() {
+    let m: Mat4 = Mat4::from_cols_array(&[
+        1.0, 2.0, 3.0, 4.0,
+        5.0, 6.0, 7.0, 8.0,
+        9.0, 10.0, 11.0, 12.0,
+        13.0, 14.0, 15.0, 16.0,
+    ]);
+    let v: Vec4 = Vec4::new(1.0, -1.0, 0.5, 2.0);
+    assert_eq!(m * v, combine(m, v));
+    assert_eq!(m * v, Vec4::new(26.5, 29.0, 31.5, 34.0));
+    assert!((sample() * Vec4::new(0.3, 0.9, -1.2, 1.0)).abs_diff_eq(combine(sample(), Vec4::new(0.3, 0.9, -1.2, 1.0)), 1e-6));
+}
+
+#[test]
+fn mat4_mul_mat4_matches_the_column_combination() {
+    let a: Mat4 = sample();
+    let b: Mat4 = Mat4::perspective_left(1.2, 1.5, 0.1, 100.0);
+    let expected: Mat4 = Mat4::from_cols(combine(a, b.col1), combine(a, b.col2), combine(a, b.col3), combine(a, b.col4));
+    assert!((a * b).abs_diff_eq(expected, 1e-6));
+    assert!((a * Mat4::I).abs_diff_eq(a, 1e-6));
+    assert!((a * a.inverse()).abs_diff_eq(Mat4::I, 1e-5));
+}
@@ -0,0 +1,9 @@
+use rgla::Vec2;
+
+#[test]
+fn clamp_length() {
+    let v: Vec2 = Vec2::new(3.0, 4.0);
+    assert_eq!(v.clamp_length(1.0, 2.0), Vec2::new(1.2, 1.6));
+    assert_eq!(v.clamp_length(10.0, 20.0), Vec2::new(6.0, 8.0));
+    assert_eq!(Vec2::ZERO.clamp_length(1.0, 2.0), Vec2::ZERO);
+}
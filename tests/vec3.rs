@@ -0,0 +1,32 @@
+use rgla::Vec3;
+
+fn components(vec: Vec3) -> (f32, f32, f32) {
+    (vec.i, vec.j, vec.k)
+}
+
+#[test]
+fn cross_follows_the_right_hand_rule() {
+    assert_eq!(components(Vec3::I.cross(Vec3::J)), (0.0, 0.0, 1.0));
+    assert_eq!(components(Vec3::J.cross(Vec3::K)), (1.0, 0.0, 0.0));
+    assert_eq!(components(Vec3::K.cross(Vec3::I)), (0.0, 1.0, 0.0));
+    assert_eq!(components(Vec3::J.cross(Vec3::I)), (0.0, 0.0, -1.0));
+}
+
+#[test]
+fn cross_is_perpendicular_to_both_inputs() {
+    let u: Vec3 = Vec3::new(1.0, 2.0, 3.0);
+    let v: Vec3 = Vec3::new(-4.0, 0.5, 2.0);
+    let c: Vec3 = u.cross(v);
+    assert_eq!(components(c), (2.5, -14.0, 8.5));
+    assert_eq!(c.i * u.i + c.j * u.j + c.k * u.k, 0.0);
+    assert_eq!(c.i * v.i + c.j * v.j + c.k * v.k, 0.0);
+}
+
+#[test]
+fn clamp_length() {
+    let v: Vec3 = Vec3::new(3.0, 0.0, 4.0);
+    assert_eq!(v.clamp_length(1.0, 2.0), Vec3::new(1.2, 0.0, 1.6));
+    assert_eq!(v.clamp_length(10.0, 20.0), Vec3::new(6.0, 0.0, 8.0));
+    assert_eq!(v.clamp_length(1.0, 20.0), v);
+    assert_eq!(Vec3::ZERO.clamp_length(1.0, 2.0), Vec3::ZERO);
+}
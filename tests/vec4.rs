@@ -0,0 +1,9 @@
+use rgla::Vec4;
+
+#[test]
+fn clamp_length() {
+    let v: Vec4 = Vec4::new(1.0, 1.0, 1.0, 1.0);
+    assert_eq!(v.clamp_length(0.5, 1.0), Vec4::new(0.5, 0.5, 0.5, 0.5));
+    assert_eq!(v.clamp_length(4.0, 8.0), Vec4::new(2.0, 2.0, 2.0, 2.0));
+    assert_eq!(Vec4::ZERO.clamp_length(1.0, 2.0), Vec4::ZERO);
+}